@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use itertools::Itertools;
+use crate::{Answer, Part};
+use crate::runner::Status;
+
+pub const DEFAULT_REGISTRY: &str = "answers.txt";
+
+const HEADER: &str = "# expected answers, one per line: day <tab> part <tab> input file <tab> answer
+# newlines within an answer are written as \\n
+";
+
+type Key = (u8, Part, String);
+
+/// Confirmed answers for each (day, part, input file), stored as a tab separated text file.
+pub struct AnswerRegistry {
+    path: PathBuf,
+    entries: BTreeMap<Key, Answer>,
+}
+
+impl AnswerRegistry {
+    /// Loads the registry at `path`, a missing file is an empty registry.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let entries = content.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(index, line)| parse_entry(line).ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}: expected `day<tab>part<tab>input<tab>answer`", path.display(), index + 1),
+            )))
+            .collect::<io::Result<_>>()?;
+
+        Ok(AnswerRegistry { path, entries })
+    }
+
+    pub fn expected(&self, day: u8, part: Part, input: &Path) -> Option<&Answer> {
+        self.entries.get(&(day, part, input_key(input)))
+    }
+
+    /// All input files with at least one recorded answer for the given day.
+    pub fn inputs(&self, day: u8) -> Vec<PathBuf> {
+        self.entries.keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, _, input)| PathBuf::from(input))
+            .dedup()
+            .collect()
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        self.entries.keys().map(|(day, _, _)| *day).dedup().collect()
+    }
+
+    /// Stores `answer` as the expected one, returning the answer it replaced.
+    pub fn record(&mut self, day: u8, part: Part, input: &Path, answer: Answer) -> Option<Answer> {
+        self.entries.insert((day, part, input_key(input)), answer)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let lines = self.entries.iter()
+            .map(|((day, part, input), answer)|
+                format!("{:02}\t{}\t{}\t{}\n", day, part, input, escape(answer.as_str())))
+            .join("");
+        fs::write(&self.path, format!("{}{}", HEADER, lines))
+    }
+}
+
+/// Compares a computed answer with the expected one, if there is any.
+pub fn check(expected: Option<&Answer>, actual: &Answer) -> Status {
+    match expected {
+        None => Status::Missing,
+        Some(expected) if expected == actual => Status::Ok,
+        Some(_) => Status::Wrong,
+    }
+}
+
+fn input_key(input: &Path) -> String {
+    input.to_string_lossy().replace('\\', "/")
+}
+
+fn parse_entry(line: &str) -> Option<(Key, Answer)> {
+    let (day, part, input, answer) = line.splitn(4, '\t').collect_tuple()?;
+    let day = day.trim().parse::<u8>().ok()?;
+    let part = Part::try_from(part.trim().parse::<u8>().ok()?).ok()?;
+    Some(((day, part, input.to_string()), Answer::from(unescape(answer))))
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { chars.next(); unescaped.push('\n') }
+            ('\\', Some('\\')) => { chars.next(); unescaped.push('\\') }
+            (c, _) => unescaped.push(c),
+        }
+    }
+    unescaped
+}
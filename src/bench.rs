@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use crate::days::Day;
+use crate::memory::{self, Usage};
+use crate::params::Params;
+use crate::{LoadError, Part};
+
+/// A step of solving a day that is timed on its own.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase { Parse, PartOne, PartTwo }
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::PartOne,
+            Part::Two => Phase::PartTwo,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part 1",
+            Phase::PartTwo => "part 2",
+        })
+    }
+}
+
+/// The spread of the wall times of one phase over all runs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+    /// The heap used by the first run, if the allocations were counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Usage>,
+}
+
+impl Timing {
+    fn from_samples(day: u8, phase: Phase, mut samples: Vec<Duration>, memory: Option<Usage>) -> Self {
+        samples.sort();
+        Timing {
+            day,
+            phase,
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+            memory,
+        }
+    }
+}
+
+/// The timings of a benchmark, stored as JSON so a later run can be compared against it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub runs: usize,
+    pub timings: Vec<Timing>,
+}
+
+impl BenchReport {
+    pub fn new(runs: usize) -> Self {
+        BenchReport { runs, timings: vec![] }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json() + "\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// The timing of the same day and phase in this report.
+    pub fn timing(&self, day: u8, phase: Phase) -> Option<&Timing> {
+        self.timings.iter().find(|t| t.day == day && t.phase == phase)
+    }
+
+    /// The median of the same day and phase in this report.
+    pub fn median(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.timing(day, phase).map(|t| t.median)
+    }
+
+    /// Compares the medians and peak heap usage with `baseline`, keyed by day and phase.
+    pub fn compare(&self, baseline: &BenchReport, threshold_percent: f64) -> BTreeMap<(u8, Phase), Change> {
+        self.timings.iter()
+            .filter_map(|t| baseline.timing(t.day, t.phase).map(|before| {
+                ((t.day, t.phase), Change::new(before, t, threshold_percent))
+            }))
+            .collect()
+    }
+}
+
+/// Phases that stay below this on both sides are too noisy to count as a regression.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// Peaks that stay below this on both sides are too small to count as a regression.
+const MEMORY_FLOOR: usize = 1 << 20;
+
+/// How the median and peak heap usage of a phase moved relative to the baseline.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Change {
+    pub baseline: Duration,
+    pub percent: f64,
+    /// How the peak heap usage moved, if both sides counted it.
+    pub peak_percent: Option<f64>,
+    pub is_regression: bool,
+}
+
+impl Change {
+    fn new(baseline: &Timing, now: &Timing, threshold_percent: f64) -> Self {
+        let percent = (now.median.as_secs_f64() / baseline.median.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0;
+        let slower = percent > threshold_percent && baseline.median.max(now.median) >= NOISE_FLOOR;
+        let peaks = baseline.memory.zip(now.memory).map(|(before, now)| (before.peak, now.peak));
+        let peak_percent = peaks.map(|(before, now)| (now as f64 / before.max(1) as f64 - 1.0) * 100.0);
+        let bigger = peaks.zip(peak_percent)
+            .is_some_and(|((before, now), percent)| percent > threshold_percent && before.max(now) >= MEMORY_FLOOR);
+        Change { baseline: baseline.median, percent, peak_percent, is_regression: slower || bigger }
+    }
+}
+
+/// Parses `input` with `params` and solves both parts `runs` times each, timing every phase on
+/// its own, after one more run that measures its heap usage. The parse phase includes taking
+/// over the parameters, so the parts solve the same puzzle as `aoc run` does. There has to be at
+/// least one run.
+pub fn bench(day: &Day, input: &str, params: &Params, runs: usize) -> Result<Vec<Timing>, LoadError> {
+    assert!(runs > 0, "a benchmark needs at least one run");
+    let (solution, parse_memory) = memory::measure(|| day.load(input, params));
+    let solution = solution?;
+    let parse = (0..runs).map(|_| time(|| day.load(input, params))).collect_vec();
+
+    let mut timings = vec![Timing::from_samples(day.number, Phase::Parse, parse, parse_memory)];
+    for part in Part::all() {
+        let (_, part_memory) = memory::measure(|| solution.solve(part));
+        let samples = (0..runs).map(|_| time(|| solution.solve(part))).collect_vec();
+        timings.push(Timing::from_samples(day.number, part.into(), samples, part_memory));
+    }
+    Ok(timings)
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+/// Durations as whole nanoseconds in the JSON reports.
+mod nanos {
+    use std::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
@@ -0,0 +1,743 @@
+use std::{fs, iter};
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use serde_json::json;
+use advent_of_code_2022::{days, generate, input, memory, runner, scaffold, trace, visualize, Answer, LoadError, Part, Solution};
+use advent_of_code_2022::repl::Repl;
+use advent_of_code_2022::answers::{self, AnswerRegistry, DEFAULT_REGISTRY};
+use advent_of_code_2022::cancel::Token;
+use advent_of_code_2022::bench::{self, BenchReport, Phase};
+use advent_of_code_2022::days::Day;
+use advent_of_code_2022::days::day_02::{self, Day02, SecondColumn};
+use advent_of_code_2022::input::{Input, Inputs};
+use advent_of_code_2022::memory::{Bytes, CountingAllocator, Usage};
+use advent_of_code_2022::runner::{PartRun, Status};
+use advent_of_code_2022::visualize::Format;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "solutions for https://adventofcode.com/2022")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Report what the solvers do on stderr, -vv for every step (or set AOC_LOG to 1 or 2)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Write the reported events to this file instead of stderr
+    #[arg(long, global = true)]
+    trace: Option<PathBuf>,
+}
+
+/// How the results of run, verify and bench are printed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+enum Output {
+    /// A table for people
+    Text,
+    /// One JSON object per line and day, part and input, for scripts
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of a single day
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Only solve this part (1 or 2), both parts are solved otherwise
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file (- for stdin) instead of looking it up
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Write the frames of the simulation as ascii, ppm, pgm or asciicast (days 10, 23 and 24)
+        #[arg(long, value_name = "FORMAT")]
+        visualize: Option<Format>,
+        /// The directory for the frames of --visualize
+        #[arg(long, requires = "visualize", default_value = ".")]
+        out: PathBuf,
+        /// Change a puzzle parameter for every input, like `row=10` on day 15
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Solve every day and summarize, failing if an answer is wrong or a finished day panics
+        #[arg(long, conflicts_with_all = ["day", "part", "input", "visualize", "params"])]
+        all: bool,
+        /// Solve the days of --all in parallel
+        #[arg(long, requires = "all")]
+        parallel: bool,
+        /// Give up on a part after this many seconds and report it as timed out
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+        /// The answer registry --all compares with, answers.txt of the repository otherwise
+        #[arg(long, requires = "all")]
+        registry: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Output::Text)]
+        format: Output,
+    },
+    /// Solve days and compare the answers with the ones in the answer registry
+    Verify {
+        /// Days to verify, every day with a recorded answer otherwise
+        days: Vec<u8>,
+        /// The answer registry, answers.txt of the repository otherwise
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Output::Text)]
+        format: Output,
+    },
+    /// Store a confirmed answer in the answer registry
+    Record {
+        day: u8,
+        /// Only record this part (1 or 2), both parts are recorded otherwise
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The input file the answer belongs to (- for stdin), the one that is looked up otherwise
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Record this answer instead of solving the puzzle, requires --part
+        #[arg(long, requires = "part")]
+        answer: Option<String>,
+        /// The answer registry, answers.txt of the repository otherwise
+        #[arg(long)]
+        registry: Option<PathBuf>,
+    },
+    /// Step through the simulation of a day (9, 10, 14, 23 or 24), reading commands from stdin
+    Repl {
+        day: u8,
+        /// Step through the simulation of this part (1 or 2)
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Read the puzzle input from this file instead of looking it up
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Change a puzzle parameter, like `crt_width=20` on day 10
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Give up on a step or run command after this many seconds
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Analyse a strategy guide of day 2: its best, worst and random totals and how strategies fare against it
+    Guide {
+        /// Read the guide from this file (- for stdin) instead of looking it up
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Change a parameter of the game, like `own_symbols=P,Q,R`
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Generate the module, input files and sample test of a new day, refusing to overwrite anything
+    New {
+        day: u8,
+    },
+    /// Write a random input for a day, to see how its solution scales
+    Gen {
+        day: u8,
+        /// How big the input is, in the unit of the day: elves, monkeys, blizzards, …
+        #[arg(long)]
+        size: usize,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of days on their default input
+    Bench {
+        /// Days to benchmark, every day with a recorded answer otherwise
+        days: Vec<u8>,
+        /// How often each phase is run, at least once
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Also write the timings as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare the medians with the ones in this JSON file of an earlier benchmark
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown against the baseline, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Change a puzzle parameter for every input, like `row=10` on day 15
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// The answer registry, answers.txt of the repository otherwise
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Output::Text)]
+        format: Output,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(e) = trace::init(cli.verbose, cli.trace.as_deref()) {
+        eprintln!("could not write the trace: {}", e);
+        return ExitCode::FAILURE;
+    }
+    let result = match cli.command {
+        Command::Run { all: true, parallel, timeout, registry, format, .. } =>
+            run_all(parallel, timeout, &registry_path(registry), format),
+        Command::Run { day, part, input, visualize, out, params, timeout, format, .. } =>
+            run(day.unwrap(), part, input, visualize, &out, &params, timeout, format),
+        Command::Verify { days, registry, format } => verify(days, &registry_path(registry), format),
+        Command::Record { day, part, input, answer, registry } =>
+            record(day, part, input, answer, &registry_path(registry)),
+        Command::Bench { days, runs, json, baseline, threshold, params, registry, format } =>
+            bench(days, runs as usize, json, baseline, threshold, &params, &registry_path(registry), format),
+        Command::Repl { day, part, input, params, timeout } => repl(day, part, input, &params, timeout),
+        Command::Guide { input, params } => guide(input, &params),
+        Command::New { day } => new(day),
+        Command::Gen { day, size, seed, out } => gen(day, size, seed, out),
+    };
+    trace::flush();
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        ExitCode::FAILURE
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run(day: u8, part: Option<u8>, input: Option<PathBuf>, visualize: Option<Format>, out: &Path, params: &[(String, String)], timeout: Option<Duration>, format: Output) -> Result<ExitCode, String> {
+    let day = find_day(day)?;
+    let Inputs { mut inputs, skipped } = match input {
+        Some(path) => Inputs { inputs: vec![resolve(day, Some(&path))?], skipped: vec![] },
+        None => input::resolve_all(day.number).map_err(|e| e.to_string())?,
+    };
+    for input in &mut inputs {
+        for (name, value) in params {
+            input.params.set(name, value);
+        }
+    }
+    let registry_path = registry_path(None);
+    let registry = AnswerRegistry::load(&registry_path)
+        .map_err(|e| format!("could not load {}: {}", registry_path.display(), e))?;
+    let mut exit = ExitCode::SUCCESS;
+    for input in &inputs {
+        // with several inputs every answer says which one it belongs to
+        let label = (inputs.len() + skipped.len() > 1).then(|| input.key());
+        let solution = match load(day, input) {
+            Ok(solution) => solution,
+            // scripts get a record saying why instead of nothing at all
+            Err(e) if format == Output::Json => {
+                for part in selected_parts(part) {
+                    let status = Status::InvalidInput(e.clone());
+                    let run = PartRun { day: day.number, part, input: Some(input.key().to_path_buf()), answer: None, status, time: None, memory: None };
+                    println!("{}", run_record(&run, &registry));
+                }
+                exit = ExitCode::FAILURE;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        for part in selected_parts(part) {
+            let name = match label.and_then(Path::file_stem) {
+                Some(stem) => format!("day_{:02}_{}_part_{}", day.number, stem.to_string_lossy(), part),
+                None => format!("day_{:02}_part_{}", day.number, part),
+            };
+            if let Some(visualize) = visualize {
+                let sink = visualize::sink(visualize, out, &name)
+                    .map_err(|e| format!("could not write to {}: {}", out.display(), e))?;
+                visualize::start(sink);
+            }
+            let token = timeout.map_or_else(Token::none, Token::with_budget);
+            let start = Instant::now();
+            let result = runner::catch_quietly(|| memory::measure(|| solution.solve_until(part, &token)));
+            let time = start.elapsed();
+            if let Some(visualize) = visualize {
+                match visualize::finish().map_err(|e| format!("could not write {} frames: {}", visualize, e))? {
+                    0 => eprintln!("day {:02} part {} has nothing to visualize", day.number, part),
+                    n => eprintln!("wrote {} {} frames of {} to {}", n, visualize, name, out.display()),
+                }
+            }
+            let outcome = match result {
+                Ok((Ok(answer), memory)) => Ok((answer, memory)),
+                Ok((Err(_), _)) => Err(Status::Timeout),
+                Err(status) => Err(status),
+            };
+            let (answer, memory) = match (outcome, format) {
+                (Ok(solved), _) => solved,
+                (Err(status), Output::Text) => {
+                    match status {
+                        Status::Timeout => eprintln!("day {:02} part {} gave up after {:.2?}", day.number, part, time),
+                        Status::Panicked(message) => eprintln!("day {:02} part {} panicked: {}", day.number, part, message),
+                        status => eprintln!("day {:02} part {} is {}", day.number, part, status),
+                    }
+                    exit = ExitCode::FAILURE;
+                    continue;
+                }
+                (Err(status), Output::Json) => {
+                    let input = Some(input.key().to_path_buf());
+                    let run = PartRun { day: day.number, part, input, answer: None, status, time: Some(time), memory: None };
+                    println!("{}", run_record(&run, &registry));
+                    exit = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            match format {
+                Output::Text => print_answer(day.number, part, label, &answer),
+                Output::Json => {
+                    let status = answers::check(registry.expected(day.number, part, input.key()), &answer);
+                    let input = Some(input.key().to_path_buf());
+                    let run = PartRun { day: day.number, part, input, answer: Some(answer), status, time: Some(time), memory };
+                    println!("{}", run_record(&run, &registry));
+                }
+            }
+        }
+    }
+    // the inputs next to them that could not be used are reported rather than left out
+    for (path, problem) in &skipped {
+        let status = Status::from(problem);
+        if status.is_regression() {
+            exit = ExitCode::FAILURE;
+        }
+        match format {
+            Output::Text => eprintln!("{} {}", input::key(path).display(), problem),
+            Output::Json => for part in selected_parts(part) {
+                let input = Some(input::key(path).to_path_buf());
+                let run = PartRun { day: day.number, part, input, answer: None, status: status.clone(), time: None, memory: None };
+                println!("{}", run_record(&run, &registry));
+            },
+        }
+    }
+    Ok(exit)
+}
+
+fn run_all(parallel: bool, timeout: Option<Duration>, registry_path: &Path, format: Output) -> Result<ExitCode, String> {
+    let registry = AnswerRegistry::load(registry_path)
+        .map_err(|e| format!("could not load {}: {}", registry_path.display(), e))?;
+    let runs = runner::run_all(days::ALL, &registry, parallel, timeout);
+    let exit = if runs.iter().any(|run| run.status.is_regression()) { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+
+    if format == Output::Json {
+        for run in &runs {
+            println!("{}", run_record(run, &registry));
+        }
+        return Ok(exit);
+    }
+
+    println!("{:>3}  {:>4}  {:<28}  {:<22}  {:<13}  {:>10}  {:>10}  {:>10}", "day", "part", "input", "answer", "status", "time", "peak", "allocs");
+    for run in &runs {
+        let answer = match (&run.answer, &run.status) {
+            (Some(answer), _) => abbreviate(answer),
+            (None, Status::Panicked(message) | Status::InvalidInput(message)) =>
+                abbreviate(&Answer::from(message.as_str())),
+            (None, _) => "-".to_string(),
+        };
+        let time = run.time.map(|time| format!("{:.2?}", time)).unwrap_or_else(|| "-".to_string());
+        let input = run.input.as_ref().map(|input| input.display().to_string()).unwrap_or_else(|| "-".to_string());
+        let (peak, allocations) = memory_columns(run.memory);
+        println!("{:>3}  {:>4}  {:<28}  {:<22}  {:<13}  {:>10}  {:>10}  {:>10}", run.day, run.part, input, answer, run.status, time, peak, allocations);
+    }
+
+    let total = runs.iter().filter_map(|run| run.time).sum::<Duration>();
+    let counts = runs.iter().counts_by(|run| run.status.to_string());
+    let summary = counts.iter().sorted().map(|(status, count)| format!("{} {}", count, status)).join(", ");
+    println!("\n{} in {:.2?}", summary, total);
+    if parallel {
+        println!("peak heap usage and allocations are not measured with --parallel");
+    }
+    Ok(exit)
+}
+
+fn verify(selected_days: Vec<u8>, registry_path: &Path, format: Output) -> Result<ExitCode, String> {
+    let registry = AnswerRegistry::load(registry_path)
+        .map_err(|e| format!("could not load {}: {}", registry_path.display(), e))?;
+
+    let selected_days = if selected_days.is_empty() { registry.days() } else { selected_days };
+    let mut rows = vec![];
+    for day in selected_days {
+        let day = find_day(day)?;
+        // a day without any input has nothing to verify, but a recorded input has to be there
+        // unless it is among the skipped ones, which get a row of their own
+        let Inputs { inputs, skipped } = input::resolve_all(day.number).unwrap_or_default();
+        let recorded = registry.inputs(day.number).into_iter()
+            .map(|path| input::repo_dir().join(path))
+            .filter(|path| !skipped.iter().any(|(skipped, _)| skipped == path))
+            .map(|path| resolve(day, Some(&path)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let inputs = inputs.into_iter()
+            .chain(recorded)
+            .unique_by(|input| input.path.clone())
+            .collect_vec();
+        for input in inputs {
+            // the text table stops at the diagnostic, the records go on with the next input
+            let solution = match load(day, &input) {
+                Ok(solution) => Ok(solution),
+                Err(e) if format == Output::Json => Err(Status::InvalidInput(e)),
+                Err(e) => return Err(e),
+            };
+            for part in Part::all() {
+                let expected = registry.expected(day.number, part, input.key());
+                let outcome = solution.as_ref().map(|solution| {
+                    let start = Instant::now();
+                    let actual = solution.solve(part);
+                    let time = start.elapsed();
+                    let status = answers::check(expected, &actual);
+                    (actual, status, time)
+                }).map_err(Status::clone);
+                rows.push((day.number, part, input.key().to_path_buf(), expected.cloned(), outcome));
+            }
+        }
+        for (path, problem) in &skipped {
+            for part in Part::all() {
+                let input = input::key(path).to_path_buf();
+                let expected = registry.expected(day.number, part, &input).cloned();
+                rows.push((day.number, part, input, expected, Err(Status::from(problem))));
+            }
+        }
+    }
+
+    let status = |outcome: &Result<(Answer, Status, Duration), Status>| match outcome {
+        Ok((_, status, _)) | Err(status) => status.clone(),
+    };
+    let count = |wanted: Status| rows.iter().filter(|row| status(&row.4) == wanted).count();
+    let exit = if rows.iter().any(|row| status(&row.4).is_regression()) { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+    if format == Output::Json {
+        for (day, part, input, expected, outcome) in &rows {
+            let record = match outcome {
+                Ok((actual, status, time)) => json!({
+                    "day": day,
+                    "part": part.number(),
+                    "input": input,
+                    "answer": actual.as_str(),
+                    "expected": expected.as_ref().map(Answer::as_str),
+                    "status": status.to_string(),
+                    "time_ns": time.as_nanos() as u64,
+                    "error": null,
+                }),
+                Err(status) => json!({
+                    "day": day,
+                    "part": part.number(),
+                    "input": input,
+                    "answer": null,
+                    "expected": expected.as_ref().map(Answer::as_str),
+                    "status": status.to_string(),
+                    "time_ns": null,
+                    "error": match status {
+                        Status::InvalidInput(error) => Some(error),
+                        _ => None,
+                    },
+                }),
+            };
+            println!("{}", record);
+        }
+        return Ok(exit);
+    }
+
+    println!("{:>3}  {:>4}  {:<28}  {:<22}  {:<22}  status", "day", "part", "input", "expected", "actual");
+    for (day, part, input, expected, outcome) in &rows {
+        let actual = match outcome {
+            Ok((actual, _, _)) => abbreviate(actual),
+            Err(Status::InvalidInput(error)) => abbreviate(&Answer::from(error.as_str())),
+            Err(_) => "-".to_string(),
+        };
+        let expected = expected.as_ref().map(abbreviate).unwrap_or_else(|| "-".to_string());
+        println!("{:>3}  {:>4}  {:<28}  {:<22}  {:<22}  {}", day, part, input.display(), expected, actual, status(outcome));
+    }
+
+    let unsolved = rows.iter().filter(|row| row.4.is_err()).counts_by(|row| status(&row.4).to_string());
+    let unsolved = unsolved.iter().sorted().map(|(status, count)| format!(", {} {}", count, status)).join("");
+    println!("\n{} ok, {} wrong, {} missing{}", count(Status::Ok), count(Status::Wrong), count(Status::Missing), unsolved);
+    Ok(exit)
+}
+
+fn record(day: u8, part: Option<u8>, input: Option<PathBuf>, answer: Option<String>, registry_path: &Path) -> Result<ExitCode, String> {
+    let day = find_day(day)?;
+    let input = resolve(day, input.as_deref())?;
+    let mut registry = AnswerRegistry::load(registry_path)
+        .map_err(|e| format!("could not load {}: {}", registry_path.display(), e))?;
+
+    let answers = match answer {
+        Some(answer) => vec![(Part::try_from(part.unwrap())?, Answer::from(answer))],
+        None => {
+            let solution = load(day, &input)?;
+            selected_parts(part).into_iter().map(|part| (part, solution.solve(part))).collect()
+        }
+    };
+
+    for (part, answer) in answers {
+        print_answer(day.number, part, None, &answer);
+        if let Some(previous) = registry.record(day.number, part, input.key(), answer) {
+            println!("  replaces {}", abbreviate(&previous));
+        }
+    }
+    registry.save().map_err(|e| format!("could not write {}: {}", registry_path.display(), e))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bench(selected_days: Vec<u8>, runs: usize, json: Option<PathBuf>, baseline: Option<PathBuf>, threshold: f64, params: &[(String, String)], registry_path: &Path, format: Output) -> Result<ExitCode, String> {
+    let selected_days = if selected_days.is_empty() {
+        AnswerRegistry::load(registry_path)
+            .map_err(|e| format!("could not load {}: {}", registry_path.display(), e))?
+            .days()
+    } else {
+        selected_days
+    };
+    let baseline = baseline
+        .map(|path| BenchReport::load(&path).map_err(|e| format!("could not load {}: {}", path.display(), e)))
+        .transpose()?;
+
+    let mut report = BenchReport::new(runs);
+    let mut inputs = vec![];
+    for day in selected_days {
+        let day = find_day(day)?;
+        let mut input = resolve(day, None)?;
+        for (name, value) in params {
+            input.params.set(name, value);
+        }
+        let timings = bench::bench(day, &input.text, &input.params, runs).map_err(|e| diagnostic(e, &input));
+        // the text table stops at the diagnostic, the records go on with the next day
+        match timings {
+            Ok(timings) => {
+                inputs.push((day.number, input.key().to_path_buf(), None));
+                report.timings.extend(timings);
+            }
+            Err(e) if format == Output::Json => inputs.push((day.number, input.key().to_path_buf(), Some(e))),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let changes = baseline.as_ref()
+        .map(|baseline| report.compare(baseline, threshold))
+        .unwrap_or_default();
+    if let Some(path) = json {
+        report.save(&path).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+    }
+    let regressions = changes.values().filter(|c| c.is_regression).count();
+    let invalid = inputs.iter().any(|(_, _, error)| error.is_some());
+    let exit = if regressions > 0 || invalid { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+
+    if format == Output::Json {
+        let phases = iter::once(Phase::Parse).chain(Part::all().map(Phase::from)).collect_vec();
+        let mut timings = report.timings.iter();
+        for (day, input, error) in &inputs {
+            if let Some(error) = error {
+                for phase in &phases {
+                    println!("{}", json!({
+                        "day": day,
+                        "phase": phase,
+                        "input": input,
+                        "status": Status::InvalidInput(String::new()).to_string(),
+                        "error": error,
+                    }));
+                }
+                continue;
+            }
+            for timing in timings.by_ref().take(phases.len()) {
+                let change = changes.get(&(timing.day, timing.phase));
+                println!("{}", json!({
+                    "day": timing.day,
+                    "phase": timing.phase,
+                    "input": input,
+                    "status": Status::Ok.to_string(),
+                    "min_ns": timing.min.as_nanos() as u64,
+                    "median_ns": timing.median.as_nanos() as u64,
+                    "max_ns": timing.max.as_nanos() as u64,
+                    "peak_bytes": timing.memory.map(|usage| usage.peak),
+                    "allocations": timing.memory.map(|usage| usage.allocations),
+                    "baseline_median_ns": change.map(|c| c.baseline.as_nanos() as u64),
+                    "change_percent": change.map(|c| c.percent),
+                    "peak_change_percent": change.and_then(|c| c.peak_percent),
+                    "regression": change.is_some_and(|c| c.is_regression),
+                    "error": null,
+                }));
+            }
+        }
+        return Ok(exit);
+    }
+
+    println!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  change", "day", "phase", "min", "median", "max", "peak", "allocs", "baseline");
+    for timing in &report.timings {
+        let change = changes.get(&(timing.day, timing.phase));
+        let baseline = change.map(|c| format!("{:.2?}", c.baseline)).unwrap_or_else(|| "-".to_string());
+        let change = match change {
+            Some(c) => {
+                let peak = c.peak_percent.map(|percent| format!(", peak {:+.1}%", percent)).unwrap_or_default();
+                let regression = if c.is_regression { " regression" } else { "" };
+                format!("{:+.1}%{}{}", c.percent, peak, regression)
+            }
+            None => "-".to_string(),
+        };
+        let (peak, allocations) = memory_columns(timing.memory);
+        println!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {}", timing.day, timing.phase,
+                 format!("{:.2?}", timing.min), format!("{:.2?}", timing.median), format!("{:.2?}", timing.max),
+                 peak, allocations, baseline, change);
+    }
+
+    if regressions > 0 {
+        println!("\n{} regressions over {}%", regressions, threshold);
+    }
+    Ok(exit)
+}
+
+fn guide(input: Option<PathBuf>, params: &[(String, String)]) -> Result<ExitCode, String> {
+    let day = find_day(2)?;
+    let mut input = resolve(day, input.as_deref())?;
+    for (name, value) in params {
+        input.params.set(name, value);
+    }
+    input.params.check(day.params()).map_err(|e| diagnostic(LoadError::Params(e), &input))?;
+    let solution = Day02::load(&input.text, &input.params).map_err(|e| diagnostic(e, &input))?;
+
+    let (part_one, part_two) = (solution.total(SecondColumn::Shapes), solution.total(SecondColumn::Outcomes));
+    println!("{}: {} rounds, scoring {} in part one and {} in part two", input.key().display(), solution.opponent().len(), part_one, part_two);
+    println!("any choice of shapes scores between {} and {}", solution.worst_total(), solution.best_total());
+    let random = solution.random_totals();
+    println!("shapes at random score {:.1} on average, {} most likely, and {} or more {:.1}% of the time",
+             random.mean(), random.most_likely(), part_one, 100.0 * random.at_least(part_one));
+
+    let tournament = solution.tournament(day_02::strategies(solution.game()));
+    println!("\n{:<16}  {:>6}  {:>6}  {:>6}  {:>8}", "strategy", "won", "drawn", "lost", "score");
+    for (name, record) in tournament.against_opponent() {
+        println!("{:<16}  {:>5.1}%  {:>5.1}%  {:>5.1}%  {:>8}", name,
+                 100.0 * record.win_rate(), 100.0 * record.draw_rate(), 100.0 * record.loss_rate(), record.score);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new(day: u8) -> Result<ExitCode, String> {
+    let root = input::repo_dir();
+    let paths = scaffold::new_day(root, day).map_err(|e| e.to_string())?;
+    for path in paths {
+        println!("wrote {}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn gen(day: u8, size: usize, seed: u64, out: Option<PathBuf>) -> Result<ExitCode, String> {
+    let generator = generate::get(day).ok_or_else(|| match days::get(day) {
+        Some(_) => format!("day {} is not solved yet, so there is nothing to generate", day),
+        None => format!("there is no day {}", day),
+    })?;
+    let input = generator.generate(size, seed);
+    match out {
+        Some(path) => {
+            fs::write(&path, input).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+            eprintln!("wrote {} {} to {}", size, generator.unit, path.display());
+        }
+        None => print!("{}", input),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn repl(day: u8, part: u8, input: Option<PathBuf>, params: &[(String, String)], timeout: Option<Duration>) -> Result<ExitCode, String> {
+    let day = find_day(day)?;
+    if input.as_deref() == Some(Path::new("-")) {
+        return Err("the repl reads its commands from stdin, the input has to come from a file".to_string());
+    }
+    let mut input = resolve(day, input.as_deref())?;
+    for (name, value) in params {
+        input.params.set(name, value);
+    }
+    let solution = load(day, &input)?;
+    let part = Part::try_from(part).unwrap();
+    if solution.simulate(part).is_none() {
+        return Err(format!("day {:02} has no simulation to step through", day.number));
+    }
+    eprintln!("day {:02} part {} on {}, `help` lists the commands", day.number, part, input.key().display());
+    let stdin = io::stdin();
+    Repl::new(|| solution.simulate(part).unwrap())
+        .with_budget(timeout)
+        .run(stdin.lock(), io::stdout(), stdin.is_terminal())
+        .map_err(|e| format!("could not talk to the terminal: {}", e))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn find_day(day: u8) -> Result<&'static Day, String> {
+    days::get(day).ok_or_else(|| format!("there is no day {}", day))
+}
+
+fn resolve(day: &Day, explicit: Option<&Path>) -> Result<Input, String> {
+    input::resolve(day.number, explicit).map_err(|e| e.to_string())
+}
+
+fn load(day: &Day, input: &Input) -> Result<Box<dyn Solution>, String> {
+    day.load(&input.text, &input.params).map_err(|e| diagnostic(e, input))
+}
+
+/// Points at the line of the input or of its parameters that `e` is about.
+fn diagnostic(e: LoadError, input: &Input) -> String {
+    match e {
+        LoadError::Input(e) => e.in_file(&input.path).diagnostic(&input.text),
+        LoadError::Params(e) => e.diagnostic(input.params.text()),
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("expected NAME=VALUE, found `{}`", s)),
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>().ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("expected a number of seconds, found `{}`", s))
+}
+
+fn registry_path(path: Option<PathBuf>) -> PathBuf {
+    path.unwrap_or_else(|| input::repo_dir().join(DEFAULT_REGISTRY))
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![Part::try_from(part).unwrap()],
+        None => Part::all().to_vec(),
+    }
+}
+
+fn print_answer(day: u8, part: Part, input: Option<&Path>, answer: &Answer) {
+    let input = input.map(|input| format!(" ({})", input.display())).unwrap_or_default();
+    if answer.as_str().contains('\n') {
+        println!("day {:02} part {}{}:\n{}", day, part, input, answer);
+    } else {
+        println!("day {:02} part {}{}: {}", day, part, input, answer);
+    }
+}
+
+/// A line of `--format json` for a solved part, with the answer recorded for it in `registry`.
+fn run_record(run: &PartRun, registry: &AnswerRegistry) -> serde_json::Value {
+    let error = match &run.status {
+        Status::Panicked(message) | Status::InvalidInput(message) => Some(message.as_str()),
+        _ => None,
+    };
+    let expected = run.input.as_ref().and_then(|input| registry.expected(run.day, run.part, input));
+    json!({
+        "day": run.day,
+        "part": run.part.number(),
+        "input": run.input,
+        "answer": run.answer.as_ref().map(Answer::as_str),
+        "expected": expected.map(Answer::as_str),
+        "status": run.status.to_string(),
+        "time_ns": run.time.map(|time| time.as_nanos() as u64),
+        "peak_bytes": run.memory.map(|usage| usage.peak),
+        "allocations": run.memory.map(|usage| usage.allocations),
+        "error": error,
+    })
+}
+
+/// The peak heap usage and allocation count as table cells.
+fn memory_columns(memory: Option<Usage>) -> (String, String) {
+    match memory {
+        Some(usage) => (Bytes(usage.peak).to_string(), usage.allocations.to_string()),
+        None => ("-".to_string(), "-".to_string()),
+    }
+}
+
+/// Shortens an answer to a single table cell.
+fn abbreviate(answer: &Answer) -> String {
+    let single_line = answer.as_str().replace('\n', "\\n");
+    if single_line.chars().count() > 22 {
+        format!("{}…", single_line.chars().take(21).collect::<String>())
+    } else {
+        single_line
+    }
+}
@@ -0,0 +1,58 @@
+//! Stopping solvers that take too long, or whose result is no longer wanted.
+//!
+//! A [`Token`] is handed to [`Solution::solve_until`](crate::Solution::solve_until). Solvers that
+//! can run for minutes check it in their loops and give up with [`Cancelled`] once the time
+//! budget of the token is used up or someone called [`Token::cancel`].
+
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Shared by everybody who may cancel or has to stop, clones see the same cancellation.
+#[derive(Clone, Debug, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    /// A token that is only cancelled by [`Token::cancel`].
+    pub fn none() -> Self {
+        Token::default()
+    }
+
+    /// A token that is cancelled once `budget` has passed from now, never for a budget too far
+    /// out for the clock.
+    pub fn with_budget(budget: Duration) -> Self {
+        Token { deadline: Instant::now().checked_add(budget), ..Token::default() }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// `Err` once the token is cancelled, to be passed on with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+/// A solver gave up because its token was cancelled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad("cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
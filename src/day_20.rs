@@ -1,7 +0,0 @@
-use std::fs;
-use itertools::Itertools;
-
-fn main() {
-    let input = fs::read_to_string("input/day_20.txt").unwrap();
-    todo!("not yet implemented")
-}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError};
+
+pub struct Day01 {
+    /// The three largest totals, largest first.
+    top_three: Vec<usize>,
+}
+
+impl Solution for Day01 {
+    /// Ranks the totals as [`top_k`] does, but from the whole inventory in memory, as the runner
+    /// reads every input. An inventory larger than memory needs [`top_k`] on a reader of the file.
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let top_three = top_k(input.as_bytes(), 3)?;
+        if top_three.is_empty() {
+            return Err(ParseError::end_of(input, "the calories of an elf"));
+        }
+        Ok(Day01 { top_three })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.top_three[0].into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.top_three.iter().sum::<usize>().into()
+    }
+}
+
+/// The `k` largest totals of the elves in `reader`, largest first, with no more than `k` of them
+/// kept in memory however long the inventory is.
+pub fn top_k(reader: impl BufRead, k: usize) -> Result<Vec<usize>, ParseError> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for total in totals(reader) {
+        heap.push(Reverse(total?));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    Ok(heap.into_sorted_vec().into_iter().map(|Reverse(total)| total).collect())
+}
+
+/// The total calories of every elf in `reader`, see [`elves`].
+pub fn totals(reader: impl BufRead) -> impl Iterator<Item = Result<usize, ParseError>> {
+    elves(reader).map(|elf| elf.map(|elf| elf.load()))
+}
+
+/// Every elf in `reader` with its items, read a line at a time. Lines may end in `\r\n`, and any
+/// number of blank lines separate the elves.
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves { reader, line: String::new(), number: 0 }
+}
+
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    /// The number of the last line read, for the errors.
+    number: usize,
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = vec![];
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return (!items.is_empty()).then_some(Ok(Elf { items })),
+                Ok(_) => self.number += 1,
+                Err(e) => return Some(Err(ParseError { line: self.number + 1, ..ParseError::new("readable text", e.to_string()) })),
+            }
+            let line = self.line.trim_end_matches(['\r', '\n']);
+            if line.trim().is_empty() {
+                match items.is_empty() {
+                    true => continue,
+                    false => return Some(Ok(Elf { items })),
+                }
+            }
+            match parse::number::<usize>(line, line) {
+                Ok(calories) => items.push(calories),
+                Err(e) => return Some(Err(ParseError { line: self.number, ..e })),
+            }
+        }
+    }
+}
+
+/// The food items an elf carries, as their calories.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Elf {
+    pub items: Vec<usize>,
+}
+
+impl Elf {
+    /// The calories of all items together.
+    pub fn load(&self) -> usize {
+        self.items.iter().sum()
+    }
+}
+
+/// All elves of an inventory with their items, for questions about more than the largest totals.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    pub fn read(reader: impl BufRead) -> Result<Self, ParseError> {
+        Ok(Inventory { elves: elves(reader).collect::<Result<_, _>>()? })
+    }
+
+    /// The elf with the item with the most calories and those calories, the first elf if several have it.
+    pub fn heaviest_item(&self) -> Option<(usize, usize)> {
+        self.elves.iter().enumerate()
+            .flat_map(|(i, elf)| elf.items.iter().map(move |calories| (i, *calories)))
+            .rev()
+            .max_by_key(|(_, calories)| *calories)
+    }
+
+    /// The load at `percent` of the elves, by nearest rank: the smallest load that at least that
+    /// share of the elves does not exceed. `None` without elves or for a percent that is not
+    /// between 0 and 100.
+    pub fn percentile(&self, percent: f64) -> Option<usize> {
+        if !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+        let loads = self.elves.iter().map(Elf::load).sorted().collect_vec();
+        // multiplying first keeps whole ranks whole, 7.0 / 100.0 * 100.0 is a little over 7
+        let rank = (percent * loads.len() as f64 / 100.0).ceil() as usize;
+        loads.get(rank.max(1) - 1).copied()
+    }
+
+    /// The middle load, the lower one of the two in the middle for an even number of elves.
+    pub fn median_load(&self) -> Option<usize> {
+        self.percentile(50.0)
+    }
+
+    /// The elves carrying more than `threshold` calories, by their index.
+    pub fn above(&self, threshold: usize) -> Vec<usize> {
+        self.elves.iter().positions(|elf| elf.load() > threshold).collect()
+    }
+
+    /// Shares all items among `elves` elves so the heaviest load is as small as the heuristic
+    /// gets it: the largest item goes first, each to the elf carrying the least so far.
+    pub fn rebalance(&self, elves: usize) -> Plan {
+        assert!(elves > 0, "the items have to go to at least one elf");
+        let mut plan = vec![Elf::default(); elves];
+        let mut lightest = (0..elves).map(|elf| Reverse((0, elf))).collect::<BinaryHeap<_>>();
+        let items = self.elves.iter().flat_map(|elf| elf.items.iter().copied()).sorted().rev();
+        for item in items {
+            let Reverse((load, elf)) = lightest.pop().unwrap();
+            plan[elf].items.push(item);
+            lightest.push(Reverse((load + item, elf)));
+        }
+
+        let total = self.elves.iter().map(Elf::load).sum::<usize>();
+        let largest = self.heaviest_item().map(|(_, calories)| calories).unwrap_or_default();
+        Plan { elves: plan, lower_bound: total.div_ceil(elves).max(largest) }
+    }
+}
+
+/// The items shared anew by [`Inventory::rebalance`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Plan {
+    pub elves: Vec<Elf>,
+    /// No way of sharing the items has a heaviest load below this: the average load or the
+    /// largest item, whichever is more.
+    pub lower_bound: usize,
+}
+
+impl Plan {
+    pub fn max_load(&self) -> usize {
+        self.elves.iter().map(Elf::load).max().unwrap_or_default()
+    }
+
+    /// How much heavier than the lower bound the heaviest load is, as a fraction of the bound. A
+    /// plan with a gap of 0.0 meets the bound and cannot be improved.
+    pub fn gap(&self) -> f64 {
+        match self.lower_bound {
+            0 => 0.0,
+            bound => self.max_load() as f64 / bound as f64 - 1.0,
+        }
+    }
+}
@@ -0,0 +1,608 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::{Answer, LoadError, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use self::Outcome::{Draw, Lost, Won};
+
+const SHAPES: Param = Param { name: "shapes", default: "rock,paper,scissors", about: "the shapes in a circle, each beating the half of the others before it" };
+const SHAPE_SCORES: Param = Param { name: "shape_scores", default: "1,2,3", about: "the score of playing each shape" };
+const OUTCOME_SCORES: Param = Param { name: "outcome_scores", default: "0,3,6", about: "the score of losing, a draw and winning" };
+const OPPONENT_SYMBOLS: Param = Param { name: "opponent_symbols", default: "A,B,C", about: "the first column's symbol for each shape" };
+const OWN_SYMBOLS: Param = Param { name: "own_symbols", default: "X,Y,Z", about: "the second column's symbol for each shape" };
+
+pub struct Day02 {
+    /// The guide is read again whenever the game changes, as the symbols change with it.
+    text: String,
+    game: Game,
+    /// The opponent's shape and the position of the second column's symbol in every round.
+    guide: Vec<(Shape, usize)>,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let game = Game::default();
+        let guide = game.read_guide(input)?;
+        Ok(Day02 { text: input.to_string(), game, guide })
+    }
+
+    fn params() -> &'static [Param] {
+        &[SHAPES, SHAPE_SCORES, OUTCOME_SCORES, OPPONENT_SYMBOLS, OWN_SYMBOLS]
+    }
+
+    /// Reads the guide again, as the symbols may have changed. The errors of a guide that does
+    /// not fit the new symbols point into the guide rather than the parameters.
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        let game = Game::configured(params)?;
+        self.guide = game.read_guide(&self.text)?;
+        self.game = game;
+        Ok(())
+    }
+
+    /// Reads the guide with the symbols of `params` right away, so a guide with symbols other
+    /// than the puzzle's loads as long as the parameters say so.
+    fn load(input: &str, params: &Params) -> Result<Self, LoadError> {
+        let game = Game::configured(params).map_err(LoadError::Params)?;
+        let guide = game.read_guide(input).map_err(LoadError::Input)?;
+        Ok(Day02 { text: input.to_string(), game, guide })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.total(SecondColumn::Shapes).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.total(SecondColumn::Outcomes).into()
+    }
+}
+
+impl Day02 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// The total score of following the guide, reading its second column as `column` says.
+    pub fn total(&self, column: SecondColumn) -> usize {
+        self.guide.iter()
+            .map(|(opponent, own)| self.game.round(*opponent, *own, column).score(&self.game))
+            .sum()
+    }
+
+    /// The shapes the opponent plays, round by round.
+    pub fn opponent(&self) -> Vec<Shape> {
+        self.guide.iter().map(|(opponent, _)| *opponent).collect()
+    }
+
+    /// The highest total any choice of shapes gets against the opponent.
+    pub fn best_total(&self) -> usize {
+        self.opponent().iter().map(|opponent| self.game.scores_against(*opponent).max().unwrap()).sum()
+    }
+
+    /// The lowest total any choice of shapes gets against the opponent.
+    pub fn worst_total(&self) -> usize {
+        self.opponent().iter().map(|opponent| self.game.scores_against(*opponent).min().unwrap()).sum()
+    }
+
+    /// How likely every total is for a player picking each shape with the same chance in every
+    /// round, worked out exactly rather than by playing.
+    pub fn random_totals(&self) -> Distribution {
+        let n = self.game.shapes.len() as f64;
+        let mut probabilities = vec![1.0];
+        for opponent in self.opponent() {
+            let scores = self.game.scores_against(opponent).collect_vec();
+            let mut next = vec![0.0; probabilities.len() + scores.iter().max().unwrap()];
+            for (total, probability) in probabilities.iter().enumerate() {
+                for score in &scores {
+                    next[total + score] += probability / n;
+                }
+            }
+            probabilities = next;
+        }
+        Distribution { probabilities }
+    }
+
+    /// Plays every one of `strategies` and the opponent of the guide against every other, a match
+    /// as long as the guide each. The opponent is the first entrant, replaying its column.
+    pub fn tournament(&self, strategies: Vec<Box<dyn Strategy>>) -> Tournament {
+        let opponent = Box::new(Recorded(self.opponent())) as Box<dyn Strategy>;
+        let mut entrants = std::iter::once(opponent).chain(strategies).collect_vec();
+        let names = entrants.iter().map(|entrant| entrant.name(&self.game)).collect_vec();
+        let mut records = vec![vec![Record::default(); entrants.len()]; entrants.len()];
+        for (first, second) in (0..entrants.len()).tuple_combinations() {
+            let (left, right) = entrants.split_at_mut(second);
+            let (a, b) = (&mut left[first], &mut right[0]);
+            a.reset();
+            b.reset();
+            let (mut a_shapes, mut b_shapes) = (vec![], vec![]);
+            for _ in 0..self.guide.len() {
+                let (a_shape, b_shape) = (a.play(&b_shapes, &self.game), b.play(&a_shapes, &self.game));
+                records[first][second].add(Round(b_shape, a_shape), &self.game);
+                records[second][first].add(Round(a_shape, b_shape), &self.game);
+                a_shapes.push(a_shape);
+                b_shapes.push(b_shape);
+            }
+        }
+        Tournament { names, records }
+    }
+}
+
+/// A hand game of an odd number of shapes in a circle, where every shape beats the half of the
+/// others that come right before it and loses to the other half. Rock, paper and scissors in
+/// this order is the puzzle's game; rock, spock, paper, lizard and scissors is another one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    shapes: Vec<String>,
+    shape_scores: Vec<usize>,
+    /// The score of losing, a draw and winning.
+    outcome_scores: [usize; 3],
+    encoding: Encoding,
+    /// The outcome of every shape against every other, derived from their order.
+    rules: Vec<Vec<Outcome>>,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::configured(&Params::default()).unwrap()
+    }
+}
+
+impl Game {
+    pub fn configured(params: &Params) -> Result<Self, ParseError> {
+        let shapes = params.list::<String>(&SHAPES)?;
+        let n = shapes.len();
+        if n % 2 == 0 || n < 3 || shapes.iter().any(String::is_empty) {
+            return Err(params.reject(&SHAPES, "an odd number of shapes, at least three"));
+        }
+        let shape_scores = params.list(&SHAPE_SCORES)?;
+        if shape_scores.len() != n {
+            return Err(params.reject(&SHAPE_SCORES, format!("a score for each of the {} shapes", n)));
+        }
+        let outcome_scores = params.list::<usize>(&OUTCOME_SCORES)?.try_into()
+            .map_err(|_| params.reject(&OUTCOME_SCORES, "scores for losing, a draw and winning"))?;
+        let symbols = |param: &Param| {
+            let symbols = params.list::<String>(param)?;
+            match symbols.len() == n && symbols.iter().all_unique() && !symbols.iter().any(String::is_empty) {
+                true => Ok(symbols),
+                false => Err(params.reject(param, format!("a different symbol for each of the {} shapes", n))),
+            }
+        };
+        let encoding = Encoding { opponent: symbols(&OPPONENT_SYMBOLS)?, own: symbols(&OWN_SYMBOLS)? };
+
+        let rules = (0..n)
+            .map(|shape| (0..n).map(|other| match (shape + n - other) % n {
+                0 => Draw,
+                distance if distance <= n / 2 => Won,
+                _ => Lost,
+            }).collect())
+            .collect();
+        Ok(Game { shapes, shape_scores, outcome_scores, encoding, rules })
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.shapes.iter().position(|shape| shape == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0]
+    }
+
+    pub fn encoding(&self) -> &Encoding {
+        &self.encoding
+    }
+
+    /// Every encoding that makes `guide` add up to `total`, trying each way the letters of each
+    /// column can stand for the shapes. That decodes the guide of an elf who wrote down other
+    /// letters, or the same ones for other shapes, given what they scored. A column with fewer
+    /// letters than shapes is filled up with the game's symbols it does not use, one with more
+    /// has no encoding.
+    pub fn encodings_for(&self, guide: &Letters, column: SecondColumn, total: usize, token: &Token) -> Result<Vec<Encoding>, Cancelled> {
+        let n = self.shapes.len();
+        let alphabet = |letters: &[String], symbols: &[String]| (letters.len() <= n).then(|| letters.iter()
+            .chain(symbols.iter().filter(|symbol| !letters.contains(symbol)))
+            .take(n)
+            .cloned()
+            .collect_vec());
+        let (Some(opponent), Some(own)) = (alphabet(&guide.opponent, &self.encoding.opponent), alphabet(&guide.own, &self.encoding.own)) else {
+            return Ok(vec![]);
+        };
+
+        // the rounds with each pair of letters, by their position in the alphabets
+        let mut rounds = vec![vec![0; n]; n];
+        for (opponent, own) in &guide.rounds {
+            rounds[*opponent][*own] += 1;
+        }
+        let scores = (0..n)
+            .map(|opponent| (0..n).map(|own| self.round(Shape(opponent), own, column).score(self)).collect_vec())
+            .collect_vec();
+        let own_orders = (0..n).permutations(n).collect_vec();
+        let written = Encoding { opponent, own };
+        let mut encodings = vec![];
+        for opponent_order in (0..n).permutations(n) {
+            token.check()?;
+            for own_order in &own_orders {
+                let score = |(opponent, own): (usize, usize)| rounds[opponent][own] * scores[opponent_order[opponent]][own_order[own]];
+                if (0..n).cartesian_product(0..n).map(score).sum::<usize>() == total {
+                    encodings.push(written.reordered(&opponent_order, own_order));
+                }
+            }
+        }
+        Ok(encodings)
+    }
+
+    /// The round of a line of the guide with the opponent's `shape` and the symbol at `own` in
+    /// the second column.
+    fn round(&self, opponent: Shape, own: usize, column: SecondColumn) -> Round {
+        match column {
+            SecondColumn::Shapes => Round(opponent, Shape(own)),
+            SecondColumn::Outcomes => Round::from_outcome(opponent, self.outcome_of(own), self),
+        }
+    }
+
+    /// The score of every shape against `opponent`, in the order of the shapes.
+    fn scores_against(&self, opponent: Shape) -> impl Iterator<Item = usize> + '_ {
+        self.shapes().map(move |shape| Round(opponent, shape).score(self))
+    }
+
+    /// The opponent's shape and the position of the second symbol on every line of `input`.
+    fn read_guide(&self, input: &str) -> Result<Vec<(Shape, usize)>, ParseError> {
+        let Encoding { opponent: opponent_symbols, own: own_symbols } = &self.encoding;
+        let position = |symbols: &[String], symbol: &str, line: &str| symbols.iter().position(|s| s == symbol)
+            .ok_or_else(|| {
+                let expected = format!("one of {}", symbols.iter().map(|s| format!("`{}`", s)).join(", "));
+                ParseError::new(expected, symbol).within(line, symbol)
+            });
+        parse::lines(input, |line| {
+            let left = parse::word(line, 0, "the opponent's shape")?;
+            let right = parse::word(line, 1, "the second column")?;
+            Ok((Shape(position(opponent_symbols, left, line)?), position(own_symbols, right, line)?))
+        })
+    }
+
+    /// What the second column means when it says how the round has to end: the symbols before
+    /// the middle one losing, the middle one a draw and the ones after it winning.
+    fn outcome_of(&self, own: usize) -> Outcome {
+        match own.cmp(&(self.shapes.len() / 2)) {
+            Ordering::Less => Lost,
+            Ordering::Equal => Draw,
+            Ordering::Greater => Won,
+        }
+    }
+}
+
+/// What the second column of the guide stands for: the shape to play in part one, how the round
+/// has to end in part two.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SecondColumn { Shapes, Outcomes }
+
+/// The symbols of the two columns of the guide, each column in the order of the shapes of the
+/// game. In the second column they stand for the outcomes instead in part two, see
+/// [`SecondColumn`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Encoding {
+    pub opponent: Vec<String>,
+    pub own: Vec<String>,
+}
+
+impl Encoding {
+    /// The encoding where the symbol at position `i` of a column stands for the shape at
+    /// `order[i]` of that column instead.
+    fn reordered(&self, opponent_order: &[usize], own_order: &[usize]) -> Self {
+        let reorder = |symbols: &[String], order: &[usize]| {
+            let mut reordered = symbols.to_vec();
+            for (symbol, shape) in symbols.iter().zip(order) {
+                reordered[*shape] = symbol.clone();
+            }
+            reordered
+        };
+        Encoding { opponent: reorder(&self.opponent, opponent_order), own: reorder(&self.own, own_order) }
+    }
+}
+
+/// The encoding as the lines of a `.params` file.
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} = {}", OPPONENT_SYMBOLS.name, self.opponent.join(", "))?;
+        writeln!(f, "{} = {}", OWN_SYMBOLS.name, self.own.join(", "))
+    }
+}
+
+/// A guide as written, before its letters are known to stand for anything, see
+/// [`Game::encodings_for`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Letters {
+    /// The different letters of each column, in order.
+    opponent: Vec<String>,
+    own: Vec<String>,
+    /// The position of the letters of every round in their column.
+    rounds: Vec<(usize, usize)>,
+}
+
+impl Letters {
+    pub fn read(input: &str) -> Result<Self, ParseError> {
+        let lines = parse::lines(input, |line| Ok((
+            parse::word(line, 0, "the opponent's shape")?.to_string(),
+            parse::word(line, 1, "the second column")?.to_string(),
+        )))?;
+        let opponent = lines.iter().map(|(opponent, _)| opponent.clone()).sorted().dedup().collect_vec();
+        let own = lines.iter().map(|(_, own)| own.clone()).sorted().dedup().collect_vec();
+        let position = |letters: &[String], letter: &String| letters.binary_search(letter).unwrap();
+        let rounds = lines.iter().map(|(o, w)| (position(&opponent, o), position(&own, w))).collect();
+        Ok(Letters { opponent, own, rounds })
+    }
+}
+
+/// A shape of a [`Game`], by its position in the circle.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Shape(usize);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome { Lost, Draw, Won }
+
+struct Round (Shape, Shape);
+
+impl Shape {
+    pub fn against(&self, other: &Self, game: &Game) -> Outcome {
+        game.rules[self.0][other.0]
+    }
+
+    /// The shape to play against `other` for `outcome`, the first one after `other` in the circle
+    /// if several of them do.
+    pub fn from_outcome(other: &Shape, outcome: &Outcome, game: &Game) -> Self {
+        let n = game.shapes.len();
+        (0..n).map(|offset| Shape((other.0 + offset) % n))
+            .find(|shape| shape.against(other, game) == *outcome)
+            .unwrap()
+    }
+
+    fn value(&self, game: &Game) -> usize {
+        game.shape_scores[self.0]
+    }
+}
+
+impl Outcome {
+    fn value(&self, game: &Game) -> usize {
+        game.outcome_scores[*self as usize]
+    }
+}
+
+
+impl Round {
+
+    fn from_outcome(opponent: Shape, outcome: Outcome, game: &Game) -> Self {
+        let needed_shape = Shape::from_outcome(&opponent, &outcome, game);
+        Round(opponent, needed_shape)
+    }
+
+    fn score(&self, game: &Game) -> usize {
+        let Round(left, right) = self;
+        let outcome = right.against(left, game);
+        outcome.value(game) + right.value(game)
+    }
+}
+
+/// The chance of every total of a game, see [`Day02::random_totals`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Distribution {
+    /// The chance of every total, by the total.
+    pub probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    pub fn probability(&self, total: usize) -> f64 {
+        self.probabilities.get(total).copied().unwrap_or_default()
+    }
+
+    /// The chance of a total of `total` or more.
+    pub fn at_least(&self, total: usize) -> f64 {
+        self.probabilities.iter().skip(total).sum()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.probabilities.iter().enumerate().map(|(total, probability)| total as f64 * probability).sum()
+    }
+
+    /// The total with the highest chance, the lowest one if several have it.
+    pub fn most_likely(&self) -> usize {
+        self.probabilities.iter().enumerate().rev()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(total, _)| total)
+    }
+}
+
+/// A way of choosing shapes round after round, from what the other player has played.
+pub trait Strategy {
+    fn name(&self, game: &Game) -> String;
+
+    /// Forgets what was learned about the other player, before a new match.
+    fn reset(&mut self) {}
+
+    /// The shape for the next round, given the shapes the other player has played so far in
+    /// this match, one more on every call.
+    fn play(&mut self, theirs: &[Shape], game: &Game) -> Shape;
+}
+
+/// The strategies of a [`Tournament`] for `game`: one always playing each shape, then
+/// [`Cycle`], [`Frequency`] and [`Markov`].
+pub fn strategies(game: &Game) -> Vec<Box<dyn Strategy>> {
+    game.shapes().map(|shape| Box::new(Fixed(shape)) as Box<dyn Strategy>)
+        .chain([Box::new(Cycle) as Box<dyn Strategy>, Box::new(Frequency::default()), Box::new(Markov::default())])
+        .collect()
+}
+
+/// Always the same shape.
+pub struct Fixed(pub Shape);
+
+impl Strategy for Fixed {
+    fn name(&self, game: &Game) -> String {
+        format!("always {}", game.name(self.0))
+    }
+
+    fn play(&mut self, _: &[Shape], _: &Game) -> Shape {
+        self.0
+    }
+}
+
+/// Every shape in turn, in the order of the circle.
+pub struct Cycle;
+
+impl Strategy for Cycle {
+    fn name(&self, _: &Game) -> String {
+        "cycle".to_string()
+    }
+
+    fn play(&mut self, theirs: &[Shape], game: &Game) -> Shape {
+        Shape(theirs.len() % game.shapes.len())
+    }
+}
+
+/// Beats the shape the other player has played most often so far.
+#[derive(Default)]
+pub struct Frequency {
+    counts: Vec<usize>,
+}
+
+impl Strategy for Frequency {
+    fn name(&self, _: &Game) -> String {
+        "frequency".to_string()
+    }
+
+    fn reset(&mut self) {
+        self.counts.clear();
+    }
+
+    fn play(&mut self, theirs: &[Shape], game: &Game) -> Shape {
+        self.counts.resize(game.shapes.len(), 0);
+        if let Some(last) = theirs.last() {
+            self.counts[last.0] += 1;
+        }
+        Shape::from_outcome(&most_often(&self.counts), &Won, game)
+    }
+}
+
+/// Beats the shape that most often followed the other player's last one so far, or the one
+/// they played most often if that is not known yet.
+#[derive(Default)]
+pub struct Markov {
+    frequency: Frequency,
+    /// How often each shape followed each other.
+    transitions: Vec<Vec<usize>>,
+}
+
+impl Strategy for Markov {
+    fn name(&self, _: &Game) -> String {
+        "markov".to_string()
+    }
+
+    fn reset(&mut self) {
+        self.frequency.reset();
+        self.transitions.clear();
+    }
+
+    fn play(&mut self, theirs: &[Shape], game: &Game) -> Shape {
+        let n = game.shapes.len();
+        self.transitions.resize(n, vec![0; n]);
+        if let [.., previous, last] = theirs {
+            self.transitions[previous.0][last.0] += 1;
+        }
+        let fallback = self.frequency.play(theirs, game);
+        match theirs.last().map(|last| &self.transitions[last.0]) {
+            Some(followers) if followers.iter().any(|&count| count > 0) =>
+                Shape::from_outcome(&most_often(followers), &Won, game),
+            _ => fallback,
+        }
+    }
+}
+
+/// The shapes of a column of the guide, over and over.
+struct Recorded(Vec<Shape>);
+
+impl Strategy for Recorded {
+    fn name(&self, _: &Game) -> String {
+        "opponent".to_string()
+    }
+
+    fn play(&mut self, theirs: &[Shape], _: &Game) -> Shape {
+        self.0[theirs.len() % self.0.len()]
+    }
+}
+
+/// The shape with the highest count, the first one if several have it.
+fn most_often(counts: &[usize]) -> Shape {
+    Shape(counts.iter().enumerate().rev().max_by_key(|(_, count)| **count).map_or(0, |(shape, _)| shape))
+}
+
+/// The results of [`Day02::tournament`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    /// The entrants, the opponent of the guide first.
+    pub names: Vec<String>,
+    /// The record of every entrant against every other, by their position in `names`.
+    pub records: Vec<Vec<Record>>,
+}
+
+impl Tournament {
+    /// The record of the entrant at `entrant` over all its matches.
+    pub fn overall(&self, entrant: usize) -> Record {
+        self.records[entrant].iter().fold(Record::default(), |total, record| Record {
+            won: total.won + record.won,
+            draws: total.draws + record.draws,
+            lost: total.lost + record.lost,
+            score: total.score + record.score,
+        })
+    }
+
+    /// The record against the opponent of the guide of every other entrant, by name.
+    pub fn against_opponent(&self) -> Vec<(&str, Record)> {
+        self.names.iter().zip(&self.records).skip(1).map(|(name, records)| (name.as_str(), records[0])).collect()
+    }
+}
+
+/// The rounds won, drawn and lost by one player and their score.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Record {
+    pub won: usize,
+    pub draws: usize,
+    pub lost: usize,
+    pub score: usize,
+}
+
+impl Record {
+    pub fn rounds(&self) -> usize {
+        self.won + self.draws + self.lost
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.won)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.lost)
+    }
+
+    fn rate(&self, rounds: usize) -> f64 {
+        match self.rounds() {
+            0 => 0.0,
+            all => rounds as f64 / all as f64,
+        }
+    }
+
+    /// Counts `round` for the player of its second shape.
+    fn add(&mut self, round: Round, game: &Game) {
+        match round.1.against(&round.0, game) {
+            Won => self.won += 1,
+            Draw => self.draws += 1,
+            Lost => self.lost += 1,
+        }
+        self.score += round.score(game);
+    }
+}
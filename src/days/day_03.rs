@@ -0,0 +1,53 @@
+use std::collections::BTreeSet;
+use itertools::Itertools;
+use tuple::Map;
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError};
+
+pub struct Day03 {
+    rucksacks: Vec<String>,
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let rucksacks = parse::lines(input, |line| {
+            match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                Some((i, c)) => Err(ParseError::new("an item letter", c)
+                    .within(line, &line[i..i + c.len_utf8()])),
+                None if line.len() % 2 == 1 => Err(ParseError::end_of(line, "an even number of items")),
+                None => Ok(line.to_string()),
+            }
+        })?;
+        Ok(Day03 { rucksacks })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.rucksacks.iter()
+            .map(|line| line.split_at(line.len()/2))
+            .map(|tuple| tuple.map(|s| BTreeSet::from_iter(s.chars())))
+            .map(|(left, right)| left.intersection(&right).cloned().collect_vec())
+            .map(|items| items.iter().map(item_priority).sum::<usize>())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.rucksacks.iter()
+            .tuples::<(_, _,_)>()
+            .map(|tuple| tuple.map(|s| BTreeSet::from_iter(s.chars())))
+            .map(|(a, b, c)|
+                BTreeSet::from_iter(a.intersection(&b).cloned()).intersection(&c).cloned().collect_vec()
+            )
+            .map(|items| items.first().map(item_priority).unwrap())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn item_priority(item: &char) -> usize {
+    if item.is_lowercase() {
+        *item as usize - 96
+    } else {
+        *item as usize - 38
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError, Within};
+
+type Assignment = ((usize, usize), (usize, usize));
+
+pub struct Day04 {
+    assignments: Vec<Assignment>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let assignments = parse::lines(input, |line| {
+            let (left, right) = line.split_once(',')
+                .ok_or_else(|| ParseError::end_of(line, "`,` between the two ranges"))?;
+            Ok((parse_range(left).within(line, left)?, parse_range(right).within(line, right)?))
+        })?;
+        Ok(Day04 { assignments })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.assignments.iter()
+            .filter(|(l, r)| contains(l,r) || contains(r, l))
+            .count()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.assignments.iter()
+            .filter(overlap_at_all)
+            .count()
+            .into()
+    }
+}
+
+
+fn parse_range(range: &str) -> Result<(usize, usize), ParseError> {
+    let (from, to) = range.split_once('-')
+        .ok_or_else(|| ParseError::end_of(range, "`-` within the range"))?;
+    Ok((parse::number(range, from)?, parse::number(range, to)?))
+}
+
+fn contains(l: &(usize, usize), r: &(usize, usize)) -> bool {
+    let (l1, l2) = l;
+    let (r1, r2) = r;
+    r1 >= l1 && r2 <= l2
+}
+
+fn overlap_at_all(range: &&Assignment) -> bool {
+    let ((l1, l2), (r1, r2)) = range;
+    l1 <= r2 && r1 <= l2
+}
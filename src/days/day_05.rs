@@ -0,0 +1,85 @@
+use itertools::Itertools;
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError, Within};
+
+pub struct Day05 {
+    start_stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (drawing, moves) = parse::blocks(input, Ok)?.into_iter().collect_tuple()
+            .ok_or_else(|| ParseError::end_of(input, "a drawing of the stacks followed by the moves"))?;
+        let start_stacks = parse_stacks(drawing).within(input, drawing)?;
+
+        let moves = parse::lines(moves, |line| {
+            let number = |n: usize, expected: &str| -> Result<usize, ParseError> {
+                let word = parse::word(line, n, expected)?;
+                parse::number(line, word)
+            };
+            let m = Move {
+                from: number(3, "the stack to move from")?,
+                to: number(5, "the stack to move to")?,
+                count: number(1, "the number of crates to move")?,
+            };
+            match [m.from, m.to].into_iter().find(|stack| !(1..=start_stacks.len()).contains(stack)) {
+                Some(stack) => Err(ParseError::new(format!("a stack from 1 to {}", start_stacks.len()), stack.to_string())),
+                None => Ok(m),
+            }
+        }).within(input, moves)?;
+
+        Ok(Day05 { start_stacks, moves })
+    }
+
+    fn part_one(&self) -> Answer {
+        let mut stacks = self.start_stacks.clone();
+        self.moves.iter().for_each(|m| {
+            (0..m.count).for_each(|_| {
+                let taken = stacks[m.from-1].pop().unwrap();
+                stacks[m.to-1].push(taken);
+            })
+        });
+        stacks.iter().map(|s|s.last().unwrap()).join("").into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut stacks = self.start_stacks.clone();
+        self.moves.iter().for_each(|m| {
+            let mut temp = vec![];
+            (0..m.count).for_each(|_| {
+                let taken = stacks[m.from-1].pop().unwrap();
+                temp.push(taken);
+            });
+
+            temp.reverse();
+
+            temp.iter().for_each(|item| {
+                stacks[m.to-1].push(*item);
+            });
+        });
+        stacks.iter().map(|s|s.last().unwrap()).join("").into()
+    }
+}
+
+/// Reads the crate drawing bottom up, so the top crate of each stack ends up last.
+fn parse_stacks(drawing: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut rows = drawing.lines().rev();
+    let numbers = rows.next().unwrap_or_default();
+    let stack_count = numbers.split_whitespace().count();
+    let mut stacks = vec![vec![]; stack_count];
+    for row in rows {
+        for (i, c) in row.chars().skip(1).step_by(4).enumerate().filter(|(_, c)| c.is_ascii_alphabetic()) {
+            stacks.get_mut(i)
+                .ok_or_else(|| ParseError::new(format!("at most {} stacks", stack_count), c).within(drawing, row))?
+                .push(c);
+        }
+    }
+    Ok(stacks)
+}
+
+struct Move {
+    from: usize,
+    to: usize,
+    count: usize
+}
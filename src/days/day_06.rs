@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::{Answer, Solution};
+use crate::parse::ParseError;
+
+pub struct Day06 {
+    input: String,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let signal = input.trim_end();
+        match signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            Some((i, c)) => Err(ParseError::new("a lowercase letter", c).within(signal, &signal[i..i + c.len_utf8()])),
+            None => Ok(Day06 { input: signal.to_string() }),
+        }
+    }
+
+    fn part_one(&self) -> Answer {
+        find_first_marker(&self.input, 4).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_first_marker(&self.input, 14).into()
+    }
+}
+
+fn find_first_marker(input: &str, len: usize) -> usize {
+    input.chars().collect_vec()
+        .windows(len)
+        .map(|chars| chars.iter().collect::<HashSet<&char>>())
+        .enumerate()
+        .find(|(_, set)| set.len() == len)
+        .map(|(index, _)| index + len)
+        .unwrap()
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use std::str::FromStr;
+use crate::{Answer, Solution};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use self::Command::{DirNode, FileNode, IntoDir, List, JumpToRoot, MoveUp};
+
+const TOTAL_SPACE: Param = Param { name: "total_space", default: "70000000", about: "the size of the disk" };
+const MIN_NEEDED_SPACE: Param = Param { name: "min_needed_space", default: "30000000", about: "the free space the update needs" };
+
+pub struct Day07 {
+    dir_sizes: Vec<usize>,
+    total_allocated_space: usize,
+    total_space: usize,
+    min_needed_space: usize,
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let commands = parse::lines(input, Command::from_str)?;
+
+        let mut path =  vec![];
+        let mut dirs: Vec<String> = vec![];
+        let mut map: HashMap<String, usize> = HashMap::new();
+
+        commands.iter().for_each(|c|{
+            match c {
+                JumpToRoot => { path = vec![]; }
+                MoveUp => { path.pop(); },
+                IntoDir(name) => path.push(name.to_string()),
+                List => {},
+                FileNode(size) => {
+                    map.entry(dir_key(&path))
+                        .and_modify(|value| *value+=size)
+                        .or_insert(*size);
+                },
+                DirNode(name) => {
+                    dirs.push(format!("{}/{}", dir_key(&path), name))
+                }
+            };
+        });
+
+
+        let dir_sizes = dirs.iter().map(|d| {
+            map.iter()
+                .filter(|(a, _b)| *a == d || a.starts_with(&format!("{}/", d)))
+                .map(|(_, b)|b)
+                .sum::<usize>()
+        }).collect_vec();
+
+        let total_allocated_space = map.values().sum::<usize>();
+
+        Ok(Day07 {
+            dir_sizes,
+            total_allocated_space,
+            total_space: TOTAL_SPACE.default_value(),
+            min_needed_space: MIN_NEEDED_SPACE.default_value(),
+        })
+    }
+
+    fn params() -> &'static [Param] {
+        &[TOTAL_SPACE, MIN_NEEDED_SPACE]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.total_space = params.value(&TOTAL_SPACE)?;
+        self.min_needed_space = params.value(&MIN_NEEDED_SPACE)?;
+        if self.total_space < self.total_allocated_space {
+            let expected = format!("a disk of at least the {} the files take", self.total_allocated_space);
+            return Err(params.reject(&TOTAL_SPACE, expected));
+        }
+        if self.min_needed_space > self.total_space {
+            let expected = format!("at most the {} of the disk", self.total_space);
+            return Err(params.reject(&MIN_NEEDED_SPACE, expected));
+        }
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        self.dir_sizes.iter()
+            .filter(|size| **size <= 100000)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let needed_space = self.needed_space();
+
+        // deleting the root, which is not among the directories, frees everything
+        self.dir_sizes.iter().copied()
+            .chain([self.total_allocated_space])
+            .filter(|size| *size >= needed_space)
+            .min().unwrap()
+            .into()
+    }
+}
+
+impl Day07 {
+    /// The space that still has to be freed, nothing if there is enough already.
+    fn needed_space(&self) -> usize {
+        let current_free_space = self.total_space.saturating_sub(self.total_allocated_space);
+        self.min_needed_space.saturating_sub(current_free_space)
+    }
+}
+
+/// Absolute path of a directory, `""` for the root and `"/a/e"` for `/a/e`.
+fn dir_key(path: &[String]) -> String {
+    path.iter().map(|dir| format!("/{}", dir)).join("")
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    JumpToRoot,
+    MoveUp,
+    IntoDir(String),
+    List,
+    FileNode(usize),
+    DirNode(String)
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split(' ').collect_vec();
+        match (words[0], words.get(1), words.get(2)) {
+            ("$", Some(&"cd"), Some(&"/")) => Ok(JumpToRoot),
+            ("$", Some(&"cd"), Some(&"..")) => Ok(MoveUp),
+            ("$", Some(&"cd"), Some(name)) => Ok(IntoDir(name.to_string())),
+            ("$", Some(&"cd"), None) => Err(ParseError::end_of(s, "a directory to change into")),
+            ("$", Some(&"ls"), _) => Ok(List),
+            ("$", Some(command), _) => Err(ParseError::new("`cd` or `ls`", *command).within(s, command)),
+            ("$", None, _) => Err(ParseError::end_of(s, "a command")),
+            ("dir", Some(name), _) => Ok(DirNode(name.to_string())),
+            (_, None, _) => Err(ParseError::end_of(s, "a file name after the size")),
+            (size, _, _) => Ok(FileNode(parse::number(s, size)?)),
+        }
+    }
+}
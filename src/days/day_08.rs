@@ -0,0 +1,49 @@
+use ndarray::Axis;
+use crate::{Answer, Solution};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+pub struct Day08 {
+    grid: Grid<usize>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| c.to_digit(10)
+            .map(|height| height as usize)
+            .ok_or_else(|| ParseError::new("a tree height", c)))?;
+        Ok(Day08 { grid })
+    }
+
+    fn part_one(&self) -> Answer {
+        let grid = &self.grid;
+        grid.iter().map(|(tree, height)|{
+            let (x, y) = (tree.x as usize, tree.y as usize);
+            let (left, right) = grid.row(y).split_at(Axis(0),x);
+            let (top, bottom) = grid.column(x).split_at(Axis(0),y);
+
+            let from_left = left.iter().max().map(|m|m < height).unwrap_or(true);
+            let from_right = right.iter().skip(1).max().map(|m|m < height).unwrap_or(true);
+            let from_top = top.iter().max().map(|m|m < height).unwrap_or(true);
+            let from_bottom = bottom.iter().skip(1).max().map(|m|m < height).unwrap_or(true);
+
+            usize::from(from_left || from_right || from_top || from_bottom)
+        }).sum::<usize>().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let grid = &self.grid;
+        grid.iter().map(|(tree, height)|{
+            let (x, y) = (tree.x as usize, tree.y as usize);
+            let (left, right) = grid.row(y).split_at(Axis(0),x);
+            let (top, bottom) = grid.column(x).split_at(Axis(0),y);
+
+            let view_left = left.iter().rev().enumerate().find(|(_, h)| *h >= height).map(|(i, _)| i+1).unwrap_or(left.len());
+            let view_right = right.iter().skip(1).enumerate().find(|(_, h)| *h >= height).map(|(i, _)| i+1).unwrap_or(right.len() - 1);
+            let view_top = top.iter().rev().enumerate().find(|(_, h)| *h >= height).map(|(i, _)| i+1).unwrap_or(top.len());
+            let view_bottom = bottom.iter().skip(1).enumerate().find(|(_, h)| *h >= height).map(|(i, _)| i+1).unwrap_or(bottom.len() - 1);
+
+            view_left * view_right * view_bottom * view_top
+        }).max().unwrap().into()
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use std::str::FromStr;
+use crate::{Answer, Part, Solution};
+use crate::grid::{Direction, Point};
+use crate::parse::{self, ParseError};
+use crate::simulation::Simulation;
+use crate::visualize::Frame;
+use self::Direction::*;
+
+pub struct Day09 {
+    moves: Vec<Move>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let moves = parse::lines(input, Move::from_str)?;
+        Ok(Day09 { moves })
+    }
+
+    fn part_one(&self) -> Answer {
+        position_count_for_rope(Rope::new(&self.moves, 2)).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        position_count_for_rope(Rope::new(&self.moves, 10)).into()
+    }
+
+    fn simulate(&self, part: Part) -> Option<Box<dyn Simulation + '_>> {
+        let knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        Some(Box::new(Rope::new(&self.moves, knots)))
+    }
+}
+
+
+fn position_count_for_rope(mut rope: Rope) -> usize {
+    while rope.step() {}
+    rope.visited.len()
+}
+
+/// A rope pulled through the moves one step of its head at a time.
+struct Rope<'a> {
+    moves: &'a [Move],
+    /// The move being made and how many of its steps are done.
+    next: (usize, usize),
+    knots: Vec<Point>,
+    /// The positions the tail has been at.
+    visited: HashSet<Point>,
+}
+
+impl<'a> Rope<'a> {
+    fn new(moves: &'a [Move], knots: usize) -> Self {
+        Rope { moves, next: (0, 0), knots: vec![Point::ORIGIN; knots], visited: HashSet::from([Point::ORIGIN]) }
+    }
+
+    fn tail(&self) -> Point {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// `H` for the head and the number of every other knot, `T` for the tail of a rope of two.
+    fn label(&self, knot: usize) -> char {
+        match knot {
+            0 => 'H',
+            1 if self.knots.len() == 2 => 'T',
+            _ => char::from_digit(knot as u32 % 36, 36).unwrap(),
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    fn step(&mut self) -> bool {
+        while self.moves.get(self.next.0).is_some_and(|m| self.next.1 == m.steps) {
+            self.next = (self.next.0 + 1, 0);
+        }
+        let Some(m) = self.moves.get(self.next.0) else {
+            return false;
+        };
+        self.knots[0] = self.knots[0].step(m.direction);
+        (0..self.knots.len() - 1).for_each(|i| {
+            self.knots[i + 1] = follow(self.knots[i + 1], self.knots[i]);
+        });
+        self.visited.insert(self.tail());
+        self.next.1 += 1;
+        true
+    }
+
+    /// The knots over the positions of the tail as `#` and the start as `s`.
+    fn snapshot(&self) -> Frame {
+        let points = self.visited.iter().chain(&self.knots).collect_vec();
+        let min = Point::new(points.iter().map(|p| p.x).min().unwrap(), points.iter().map(|p| p.y).min().unwrap());
+        let max = Point::new(points.iter().map(|p| p.x).max().unwrap(), points.iter().map(|p| p.y).max().unwrap());
+        let mut frame = Frame::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, '.');
+        self.visited.iter().for_each(|p| frame[*p - min] = '#');
+        frame[Point::ORIGIN - min] = 's';
+        self.knots.iter().enumerate().rev().for_each(|(i, p)| frame[*p - min] = self.label(i));
+        frame
+    }
+
+    fn values(&self) -> Vec<(&'static str, isize)> {
+        vec![
+            ("move", self.next.0 as isize + 1),
+            ("head_x", self.knots[0].x),
+            ("head_y", self.knots[0].y),
+            ("tail_x", self.tail().x),
+            ("tail_y", self.tail().y),
+            ("visited", self.visited.len() as isize),
+        ]
+    }
+
+    fn entities(&self) -> Vec<String> {
+        self.knots.iter().enumerate()
+            .map(|(i, p)| format!("knot {} at {},{}", self.label(i), p.x, p.y))
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Move {
+    direction: Direction,
+    steps: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dir = parse::word(s, 0, "a direction")?;
+        let steps = parse::word(s, 1, "the number of steps")?;
+        Ok(Move {
+            direction: match dir {
+                "R" => Right,
+                "L" => Left,
+                "U" => Up,
+                "D" => Down,
+                _ => return Err(ParseError::new("one of `R`, `L`, `U`, `D`", dir)),
+            },
+            steps: parse::number(s, steps)?,
+        })
+    }
+}
+
+/// Where a knot ends up once the knot in front of it moved to `head`.
+fn follow(knot: Point, head: Point) -> Point {
+    let dist = head - knot;
+    if dist.x.abs() >= 2 || dist.y.abs() >= 2 {
+        knot + dist.signum()
+    } else {
+        knot
+    }
+}
@@ -0,0 +1,201 @@
+use itertools::Itertools;
+use std::str::FromStr;
+use std::fmt::{Display, Formatter};
+use crate::{Answer, Part, Solution};
+use crate::grid::Point;
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::simulation::Simulation;
+use crate::visualize::{self, Frame};
+
+const CRT_WIDTH: Param = Param { name: "crt_width", default: "40", about: "the pixels in a row of the crt" };
+const CHECKS: Param = Param { name: "checks", default: "20,60,100,140,180,220", about: "the cycles part one sums the signal strengths of" };
+
+pub struct Day10 {
+    commands: Vec<Command>,
+    crt_width: isize,
+    checks: Vec<usize>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day10 {
+            commands: parse::lines(input, Command::from_str)?,
+            crt_width: CRT_WIDTH.default_value(),
+            checks: CHECKS.default_list(),
+        })
+    }
+
+    fn params() -> &'static [Param] {
+        &[CRT_WIDTH, CHECKS]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.crt_width = params.value(&CRT_WIDTH)?;
+        if self.crt_width < 1 {
+            return Err(params.reject(&CRT_WIDTH, "a width of at least 1"));
+        }
+        self.checks = params.list(&CHECKS)?;
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        self.run().0.into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.run().1.into()
+    }
+
+    fn simulate(&self, _part: Part) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Cpu::new(self)))
+    }
+}
+
+impl Day10 {
+    /// Runs the program once, returning the summed signal strengths and the rendered crt.
+    fn run(&self) -> (isize, String) {
+        let mut cpu = Cpu::new(self);
+        while cpu.step() {
+            visualize::frame(|| cpu.snapshot());
+        }
+        (cpu.signal_strengths, cpu.crt.trim().to_string())
+    }
+}
+
+/// The program running a cycle at a time, drawing the crt as it goes.
+struct Cpu<'a> {
+    commands: &'a [Command],
+    /// The command being run and how many of its cycles are done.
+    next: (usize, usize),
+    /// The cycle about to start, counting from 1.
+    cycle: isize,
+    x: isize,
+    crt_width: isize,
+    /// The cycles still to check, the next one last.
+    checks: Vec<isize>,
+    signal_strengths: isize,
+    crt: String,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(day: &'a Day10) -> Self {
+        Cpu {
+            commands: &day.commands,
+            next: (0, 0),
+            cycle: 1,
+            x: 1,
+            crt_width: day.crt_width,
+            checks: day.checks.iter().sorted().rev().map(|cycle| *cycle as isize).collect_vec(),
+            signal_strengths: 0,
+            crt: String::new(),
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    fn step(&mut self) -> bool {
+        let Some(c) = self.commands.get(self.next.0) else {
+            return false;
+        };
+        if self.checks.last() == Some(&self.cycle) {
+            self.checks.pop();
+            self.signal_strengths += self.cycle * self.x;
+        }
+
+        let crt_pixel = (self.cycle - 1) % self.crt_width;
+        if crt_pixel <= self.x +1 && crt_pixel >= self.x -1 {
+            self.crt.push('█');
+        } else {
+            self.crt.push('_');
+        }
+        if self.cycle % self.crt_width == 0 {
+            self.crt.push('\n');
+        }
+        self.cycle += 1;
+
+        self.next.1 += 1;
+        if self.next.1 == c.cycles() {
+            if let Command::AddX(add) = c {
+                self.x += add;
+            }
+            self.next = (self.next.0 + 1, 0);
+        }
+        true
+    }
+
+    fn snapshot(&self) -> Frame {
+        crt_frame(&self.crt, self.crt_width as usize)
+    }
+
+    fn values(&self) -> Vec<(&'static str, isize)> {
+        vec![
+            ("cycle", self.cycle),
+            ("x", self.x),
+            ("pixel", (self.cycle - 1) % self.crt_width),
+            ("signal_strengths", self.signal_strengths),
+        ]
+    }
+
+    fn entities(&self) -> Vec<String> {
+        let mut entities = vec![format!("register x = {}", self.x)];
+        if let Some(c) = self.commands.get(self.next.0) {
+            entities.push(format!("command {}: {}, cycle {} of {}", self.next.0 + 1, c, self.next.1 + 1, c.cycles()));
+        }
+        entities
+    }
+}
+
+/// The crt as far as it is drawn, the pixels still to come blank. Programs running past the
+/// 240 cycles of the puzzle draw more rows.
+fn crt_frame(crt: &str, width: usize) -> Frame {
+    let mut frame = Frame::new(width, crt.lines().count().max(6), ' ');
+    for (y, line) in crt.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            frame[Point::new(x as isize, y as isize)] = c;
+        }
+    }
+    frame
+}
+
+
+#[derive(Debug)]
+enum Command {
+    Noop,
+    AddX(isize)
+}
+
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split(' ').collect_vec();
+        match (words[0], words.get(1)) {
+            ("noop", None) => Ok(Command::Noop),
+            ("addx", Some(v)) => Ok(Command::AddX(parse::number(s, v)?)),
+            ("addx", None) => Err(ParseError::end_of(s, "a value to add")),
+            ("noop", Some(extra)) => Err(ParseError::new("nothing after `noop`", *extra).within(s, extra)),
+            (other, _) => Err(ParseError::new("`noop` or `addx`", other)),
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Noop => write!(f, "noop"),
+            Command::AddX(add) => write!(f, "addx {}", add),
+        }
+    }
+}
+
+impl Command {
+    fn cycles(&self) -> usize {
+        match self {
+            Command::Noop => 1,
+            Command::AddX(_) => 2,
+        }
+    }
+
+}
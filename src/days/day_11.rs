@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use std::str::FromStr;
+use crate::{Answer, Solution};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError, Within};
+use crate::trace::{self, Event, Level};
+
+
+const PART_TWO_ROUNDS: Param = Param { name: "part_two_rounds", default: "10000", about: "the rounds part two plays without relief" };
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+    part_two_rounds: usize,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let monkeys = parse::blocks(input, Monkey::from_str)?;
+        if let Some(target) = monkeys.iter()
+            .flat_map(|m| [m.if_dividable, m.if_not_dividable])
+            .find(|target| *target >= monkeys.len()) {
+            return Err(ParseError::new(format!("a monkey below {}", monkeys.len()), target.to_string()));
+        }
+        Ok(Day11 { monkeys, part_two_rounds: PART_TWO_ROUNDS.default_value() })
+    }
+
+    fn params() -> &'static [Param] {
+        &[PART_TWO_ROUNDS]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.part_two_rounds = params.value(&PART_TWO_ROUNDS)?;
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        run_rounds(20, self.monkeys.clone(), true).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut monkeys = self.monkeys.clone();
+        let divisors = monkeys.iter().map(|m|m.divisor).collect_vec();
+
+        monkeys.iter_mut().for_each(|m|m.items.iter_mut()
+            .for_each(|i|i.track_remainders(divisors.clone())));
+
+        run_rounds(self.part_two_rounds, monkeys, false).into()
+    }
+}
+
+
+fn run_rounds(rounds: usize, mut monkeys: Vec<Monkey>, with_relieve: bool) -> usize {
+    let mut inspected = monkeys.iter().map(|_| 0).collect_vec();
+    for round in 1..rounds + 1 {
+        for monkey_index in 0..monkeys.len() {
+            if monkeys[monkey_index].items.is_empty() {
+                continue;
+            }
+            let items = monkeys[monkey_index].items.clone();
+            monkeys[monkey_index].items.clear();
+            items.iter().for_each(|item| {
+                inspected[monkey_index] += 1;
+
+                let mut new_value = item.clone();
+                new_value.apply(&monkeys[monkey_index].operation);
+                if with_relieve {
+                    new_value.apply(&Operation::Relieve);
+                };
+                let throw_to = monkeys[monkey_index].test(&new_value);
+                trace::emit(|| Thrown { round, from: monkey_index, to: throw_to });
+                monkeys[throw_to].items.push(new_value);
+            });
+        }
+
+        trace::emit(|| RoundFinished { round, inspected: inspected.clone() });
+    }
+    let business = inspected.iter().sorted().rev().take(2).cloned().reduce(|a, b| a * b).unwrap();
+    trace::emit(|| MonkeyBusiness { rounds, inspected: inspected.clone() });
+    business
+}
+
+struct Thrown { round: usize, from: usize, to: usize }
+
+impl Event for Thrown {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Thrown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "round {}: monkey {} throws to monkey {}", self.round, self.from, self.to)
+    }
+}
+
+struct RoundFinished { round: usize, inspected: Vec<usize> }
+
+impl Event for RoundFinished {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for RoundFinished {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "after round {} inspected {:?}", self.round, self.inspected)
+    }
+}
+
+struct MonkeyBusiness { rounds: usize, inspected: Vec<usize> }
+
+impl Event for MonkeyBusiness {
+    const LEVEL: Level = Level::Info;
+}
+
+impl Display for MonkeyBusiness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "inspected after {} rounds: {:?}", self.rounds, self.inspected)
+    }
+}
+
+#[derive(Clone)]
+enum Operation { Mul(usize), Add(usize), Quad, Relieve}
+impl Operation {
+    fn execute(&self, lhs: usize) -> usize {
+        match self {
+            Operation::Mul(v) => lhs *v,
+            Operation::Add(v) => lhs +v,
+            Operation::Quad => lhs * lhs,
+            Operation::Relieve => lhs / 3
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    divisor: usize,
+    if_dividable: usize,
+    if_not_dividable: usize,
+}
+
+impl Monkey {
+    fn test(&self, item: &Item ) -> usize{
+        if item.is_dividable_by(self.divisor) {
+            self.if_dividable
+        } else {
+            self.if_not_dividable
+        }
+    }
+}
+
+/// An item's worry level. It is tracked exactly while worry is relieved after each inspection,
+/// and as remainders per monkey divisor once it would otherwise grow without bound.
+#[derive(Debug, Clone)]
+struct Item {
+    worry: usize,
+    remainder: HashMap<usize, usize>,
+}
+
+impl Item {
+    fn new(v: usize) -> Self{
+        Item {
+            worry: v,
+            remainder: Default::default()
+        }
+    }
+    fn track_remainders(&mut self, divisors: Vec<usize>) {
+        for d in divisors {
+            let rem = self.worry % d;
+            self.remainder.insert(d, rem);
+        }
+    }
+    fn apply(&mut self, op: &Operation) {
+        if self.remainder.is_empty() {
+            self.worry = op.execute(self.worry);
+            return;
+        }
+        match op {
+            Operation::Mul(_) | Operation::Quad => {
+                self.remainder.iter_mut().for_each(|(divisor, remainder)| {
+                    *remainder = op.execute(*remainder) % divisor;
+                })
+            }
+            Operation::Add(v) => {
+                self.remainder.iter_mut().for_each(|(divisor, remainder)| {
+                    let sum = v % divisor + *remainder;
+                    *remainder =  sum % divisor;
+                });
+            }
+            Operation::Relieve => unreachable!("worry can not be relieved on remainders")
+        }
+    }
+
+    fn is_dividable_by(&self, divisor: usize) -> bool {
+        if self.remainder.is_empty() {
+            self.worry.is_multiple_of(divisor)
+        } else {
+            self.remainder[&divisor] == 0
+        }
+    }
+}
+impl From<usize> for Item {
+    fn from(v: usize) -> Self {
+        Item::new(v)
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.lines().collect_vec();
+        let row = |n: usize, expected: &str| rows.get(n).copied()
+            .ok_or_else(|| ParseError::end_of(s, expected));
+
+        let starting = row(1, "the starting items")?;
+        let (_, items) = starting.split_once(": ")
+            .ok_or_else(|| ParseError::end_of(starting, "`: ` before the items"))?;
+        let items = items.split(", ")
+            .map(|item| parse::number(starting, item).map(Item::new))
+            .collect::<Result<Vec<_>, _>>()
+            .within(s, starting)?;
+
+        let operation = row(2, "the operation")?;
+        let operation = match (last_words(operation, 2), last_word(operation)) {
+            (Some("*"), Some("old")) => Operation::Quad,
+            (Some("*"), Some(v)) => Operation::Mul(parse::number(operation, v).within(s, operation)?),
+            (Some("+"), Some(v)) => Operation::Add(parse::number(operation, v).within(s, operation)?),
+            (Some(other), Some(_)) => return Err(ParseError::new("`*` or `+`", other).within(s, operation)),
+            _ => return Err(ParseError::end_of(operation, "an operator and operand").within(s, operation)),
+        };
+
+        let number_at_end = |n: usize, expected: &str| -> Result<usize, ParseError> {
+            let line = row(n, expected)?;
+            let word = last_word(line).unwrap_or_default();
+            parse::number(line, word).within(s, line)
+        };
+        Ok(Monkey {
+            items,
+            operation,
+            divisor: number_at_end(3, "the divisor of the test")?,
+            if_dividable: number_at_end(4, "the monkey to throw to if true")?,
+            if_not_dividable: number_at_end(5, "the monkey to throw to if false")?,
+        })
+    }
+}
+
+fn last_word(line: &str) -> Option<&str> {
+    line.split(' ').next_back()
+}
+
+/// The word `n` words from the end of `line`, `1` being the last word.
+fn last_words(line: &str, n: usize) -> Option<&str> {
+    line.split(' ').nth_back(n - 1)
+}
@@ -0,0 +1,125 @@
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::{Answer, Solution};
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+use crate::search::{self, SearchProblem, Strategy};
+use self::Label::{Basic, End, Start};
+
+pub struct Day12 {
+    grid: Grid<Position>,
+    start: Node,
+    goal: Node,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, Position::try_from)?;
+        let start = grid.iter()
+            .find(|(_, pos)| pos.is_start())
+            .map(|(| coord, pos)| Node { coord, pos: pos.clone() })
+            .ok_or_else(|| ParseError::end_of(input, "the start `S`"))?;
+
+        let goal = grid.iter()
+            .find(|(_, pos)| pos.is_end())
+            .map(|(| coord, pos)| Node { coord, pos: pos.clone() })
+            .ok_or_else(|| ParseError::end_of(input, "the best signal `E`"))?;
+
+        Ok(Day12 { grid, start, goal })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.climb(self.start.clone()).unwrap().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.grid.iter()
+            .filter(|(_, pos)| pos.elevation_as_int() == 0)
+            .filter_map(|(coord, pos)| self.climb(Node { coord, pos: pos.clone() }))
+            .min().unwrap().into()
+    }
+}
+
+impl Day12 {
+    /// The fewest steps from `start` to the best signal.
+    fn climb(&self, start: Node) -> Option<usize> {
+        let climb = Climb { grid: &self.grid, start, goal: &self.goal };
+        search::search(&climb, Strategy::Bfs).path.map(|path| path.cost)
+    }
+}
+
+struct Climb<'a> {
+    grid: &'a Grid<Position>,
+    start: Node,
+    goal: &'a Node,
+}
+
+impl SearchProblem for Climb<'_> {
+    type State = Node;
+
+    fn start(&self) -> Node {
+        self.start.clone()
+    }
+
+    fn successors(&self, n: &Node) -> Vec<(Node, usize)> {
+        n.coord.neighbours().into_iter()
+            .filter_map(|c| self.grid.get(c).map(|pos| {
+                Node { coord: c, pos: pos.clone() }
+            }))
+            .filter(|succ|
+                (succ.pos.elevation_as_int() as isize - n.pos.elevation_as_int() as isize) <= 1
+            )
+            .map(|n| (n, 1))
+            .collect_vec()
+    }
+
+    fn is_goal(&self, n: &Node) -> bool {
+        n.pos.is_end()
+    }
+
+    fn heuristic(&self, n: &Node) -> usize {
+        n.coord.manhattan(self.goal.coord)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Node {
+    coord: Point,
+    pos: Position,
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Label { Start, End, Basic, }
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Position {
+    label: Label,
+    elevation: char,
+}
+
+impl Position {
+    fn is_start(&self) -> bool { matches!(self.label, Start) }
+    fn is_end(&self) -> bool { matches!(self.label, End) }
+}
+
+impl TryFrom<char> for Position {
+    type Error = ParseError;
+
+    fn try_from(s: char) -> Result<Self, Self::Error> {
+        match s {
+            'S' => Ok(Position { label: Start, elevation: 'a' }),
+            'E' => Ok(Position { label: End, elevation: 'z' }),
+            e if e.is_ascii_lowercase() => Ok(Position { label: Basic, elevation: e }),
+            other => Err(ParseError::new("an elevation from `a` to `z`, `S` or `E`", other)),
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "{}", self.elevation) }
+}
+
+
+impl Position {
+    fn elevation_as_int(&self) -> usize { self.elevation as usize - 97 }
+}
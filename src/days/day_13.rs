@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use itertools::{EitherOrBoth, Itertools};
+use std::str::FromStr;
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError};
+use crate::trace::{self, Event, Level};
+use self::Value::{Integer, List};
+
+
+// this solution hat not been "cleaned up" and shows the dirty truth
+pub struct Day13 {
+    packets: Vec<Value>,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let packets = parse::lines(input, |line| match line.trim() {
+            "" => Ok(None),
+            packet => Value::from_str(packet).map(Some),
+        })?.into_iter().flatten().collect_vec();
+        Ok(Day13 { packets })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.packets.iter()
+            .tuples::<(_, _)>()
+            .enumerate()
+            .map(|(i, (l, r))| {
+                let order = l.compare(r);
+                trace::emit(|| PairChecked { pair: i + 1, order: order.clone() });
+                (i+1, order)
+            })
+            .filter(|(_, o)| matches!(o, Order::Valid))
+            .map(|(i, _)| i)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut parsed = self.packets.clone();
+        let marker_1 = List(vec![List(vec![Integer(2)])]);
+        let marker_2 = List(vec![List(vec![Integer(6)])]);
+        parsed.push(marker_1.clone());
+        parsed.push(marker_2.clone());
+
+        parsed.sort_by(|a, b| {
+           match a.compare(b) {
+               Order::Unknown => Ordering::Equal,
+               Order::Valid => Ordering::Less,
+               Order::Wrong => Ordering::Equal
+           }
+        });
+
+        trace::emit(|| Sorted(&parsed));
+
+        parsed.iter().enumerate()
+            .filter(|(_, v)| {
+                v == &&marker_1 || v == &&marker_2
+            })
+            .map(|(i, _)| {
+                i +1
+            }).reduce(|a, b| a * b).unwrap()
+            .into()
+    }
+}
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Value {
+    List(Vec<Value>),
+    Integer(usize)
+}
+
+impl Value {
+    fn to_list(&self) -> Value {
+        match self {
+            List(v) => List(v.clone()),
+            Integer(v) => List(vec![Integer(*v)])
+        }
+    }
+
+    fn compare(&self, rhs: &Value) -> Order {
+        let o = match (self, rhs) {
+            (Integer(l), Integer(r)) => match (l,r) {
+                (l,r ) if l < r => Order::Valid,
+                (l,r ) if l > r => Order::Wrong,
+                (l,r ) if l == r => Order::Unknown,
+                _ => unreachable!()
+            },
+            (Integer(_), List(_)) => self.to_list().compare(rhs),
+            (List(_), Integer(_)) => self.compare(&rhs.to_list()),
+            (List(l), List(r)) => {
+                let pairs = l.iter()
+                    .zip_longest(r.iter());
+
+                let mut is_valid = false;
+                for pair in pairs {
+                    match pair {
+                        EitherOrBoth::Both(l, r) => {
+                            let o = l.compare(r);
+                            match o {
+                                Order::Unknown => {}
+                                Order::Valid => return o,
+                                Order::Wrong => return o
+                            }
+                        }
+                        EitherOrBoth::Right(_) => {
+                            is_valid = true;
+                        }
+                        EitherOrBoth::Left(_) => {
+                            if !is_valid {
+                                return Order::Wrong
+                            }
+                        }
+                    }
+                }
+                if is_valid {
+                    Order::Valid
+                } else {
+                    Order::Unknown
+                }
+            },
+        };
+        trace::emit(|| Compared { left: self, right: rhs, order: o.clone() });
+        o
+    }
+}
+
+struct Compared<'a> { left: &'a Value, right: &'a Value, order: Order }
+
+impl Event for Compared<'_> {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Compared<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} vs {}: {:?}", self.left, self.right, self.order)
+    }
+}
+
+struct PairChecked { pair: usize, order: Order }
+
+impl Event for PairChecked {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for PairChecked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pair {}: {:?}", self.pair, self.order)
+    }
+}
+
+struct Sorted<'a>(&'a [Value]);
+
+impl Event for Sorted<'_> {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Sorted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sorted packets: {}", self.0.iter().join(" "))
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            List(v) => write!(f, "[{}]", v.iter().map(|v| format!("{}", v)).join(",")),
+            Value::Integer(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let at = |i: usize| &line[i..];
+        let mut digits_start = None;
+        let mut open_stack = vec![];
+        for (i, c) in line.char_indices() {
+            match c {
+                '[' if digits_start.is_none() => open_stack.push(List(vec![])),
+                ']' | ',' => {
+                    if let Some(start) = digits_start.take() {
+                        let num = parse::number(line, &line[start..i])?;
+                        match open_stack.last_mut() {
+                            Some(List(list)) => list.push(Integer(num)),
+                            _ => return Err(ParseError::new("`[` before the number", &line[start..i]).within(line, at(start))),
+                        }
+                    }
+
+                    if c == ',' {
+                        continue;
+                    }
+                    let closed_list = open_stack.pop()
+                        .ok_or_else(|| ParseError::new("`[` before `]`", "]").within(line, at(i)))?;
+                    match open_stack.last_mut() {
+                        Some(List(list)) => list.push(closed_list),
+                        _ if i + 1 == line.len() => return Ok(closed_list),
+                        _ => return Err(ParseError::new("the end of the packet", at(i + 1)).within(line, at(i + 1))),
+                    }
+                },
+                digit if digit.is_ascii_digit() => { digits_start.get_or_insert(i); },
+                other => return Err(ParseError::new("`[`, `]`, `,` or a digit", other).within(line, at(i))),
+            }
+        };
+        Err(ParseError::end_of(line, "`]`"))
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+enum Order {
+    Unknown,
+    Valid,
+    Wrong
+}
@@ -0,0 +1,199 @@
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::{Answer, Part, Solution};
+use crate::grid::{Point, SparseGrid};
+use crate::grid::Direction::{Down, Left, Right};
+use crate::parse::{self, ParseError, Within};
+use crate::simulation::Simulation;
+use crate::trace::{self, Event, Level};
+use crate::visualize::Frame;
+
+
+const SOURCE: Point = Point::new(500, 0);
+
+// this solution hat not been "cleaned up" and shows the dirty truth
+pub struct Day14 {
+    rocks: SparseGrid,
+    max_y: isize,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let rock_traces = parse::lines(input, |path| {
+            let points = path.split(" -> ")
+                .map(|point| parse_point(point).within(path, point))
+                .collect::<Result<Vec<_>, _>>()?;
+            match points.iter().tuple_windows().position(|(a, b)| (a.x == b.x) == (a.y == b.y)) {
+                Some(i) => {
+                    let point = path.split(" -> ").nth(i + 1).unwrap();
+                    Err(ParseError::new(format!("a point in line with {},{}", points[i].x, points[i].y), point).within(path, point))
+                }
+                None => Ok(points),
+            }
+        })?;
+
+        let mut rocks = SparseGrid::new();
+
+        rock_traces.iter().for_each(|trace|{
+            trace.iter().tuple_windows::<(_,_)>().for_each(|(a, b)| {
+                let step = (*b - *a).signum();
+
+                let mut rock = *a;
+                rocks.insert(rock, ());
+                while rock != *b {
+                    rock = rock + step;
+                    rocks.insert(rock, ());
+                }
+                trace::emit(|| RockLine { from: *a, to: *b });
+            });
+
+        });
+
+        let max_y = rocks.points().map(|rock| rock.y).max()
+            .ok_or_else(|| ParseError::end_of(input, "a path of rock"))?;
+        Ok(Day14 { rocks, max_y })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.pour_sand(false).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.pour_sand(true).into()
+    }
+
+    fn simulate(&self, part: Part) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Sand::new(self, part == Part::Two)))
+    }
+}
+
+fn parse_point(point: &str) -> Result<Point, ParseError> {
+    let (x, y) = point.split_once(',')
+        .ok_or_else(|| ParseError::end_of(point, "`,` between x and y"))?;
+    Ok(Point::new(parse::number(point, x)?, parse::number(point, y)?))
+}
+
+impl Day14 {
+    /// Counts the units of sand that come to rest, either until one falls into the abyss
+    /// below the lowest rock or, with a floor two below it, until the source is blocked.
+    fn pour_sand(&self, with_floor: bool) -> usize {
+        let mut sand = Sand::new(self, with_floor);
+        while sand.step() {}
+        sand.resting
+    }
+}
+
+/// Sand poured from the source, a step of the falling unit at a time.
+struct Sand<'a> {
+    rocks: &'a SparseGrid,
+    max_y: isize,
+    with_floor: bool,
+    /// The rocks and the sand that came to rest.
+    world: SparseGrid,
+    /// The unit that is falling, none once the source is blocked or a unit fell into the abyss.
+    falling: Option<Point>,
+    resting: usize,
+}
+
+impl<'a> Sand<'a> {
+    fn new(day: &'a Day14, with_floor: bool) -> Self {
+        Sand { rocks: &day.rocks, max_y: day.max_y, with_floor, world: day.rocks.clone(), falling: Some(SOURCE), resting: 0 }
+    }
+}
+
+impl Simulation for Sand<'_> {
+    fn step(&mut self) -> bool {
+        let Some(sand_pos) = self.falling else {
+            return false;
+        };
+        if !self.with_floor && sand_pos.y > self.max_y {
+            self.falling = None;
+            return true;
+        }
+        let down = sand_pos.step(Down);
+        let down_left = down.step(Left);
+        let down_right = down.step(Right);
+
+        let free_spot = [down, down_left, down_right].into_iter()
+            .filter(|pos| pos.y < self.max_y + 2)
+            .find(|pos| !self.world.contains(*pos));
+
+        if let Some(free_pos) = free_spot {
+            self.falling = Some(free_pos);
+        } else {
+            self.resting += 1;
+            if sand_pos == SOURCE {
+                self.falling = None;
+            } else {
+                trace::emit(|| SandSettled { unit: self.resting, at: sand_pos });
+                self.falling = Some(SOURCE);
+            }
+            self.world.insert(sand_pos, ());
+        }
+        true
+    }
+
+    /// Rock as `#`, resting sand as `o`, the source as `+` and the falling unit as `~`.
+    fn snapshot(&self) -> Frame {
+        let floor_y = self.max_y + 2;
+        let (min, max) = self.world.bounds().unwrap();
+        let min = Point::new(min.x.min(SOURCE.x) - 1, SOURCE.y);
+        let max = Point::new(max.x.max(SOURCE.x) + 1, if self.with_floor { floor_y } else { self.max_y + 1 });
+        let mut frame = Frame::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, '.');
+        for (p, cell) in frame.iter_mut() {
+            let pos = p + min;
+            *cell = if self.rocks.contains(pos) || (self.with_floor && pos.y == floor_y) {
+                '#'
+            } else if self.world.contains(pos) {
+                'o'
+            } else if self.falling == Some(pos) {
+                '~'
+            } else if pos == SOURCE {
+                '+'
+            } else {
+                '.'
+            };
+        }
+        frame
+    }
+
+    fn values(&self) -> Vec<(&'static str, isize)> {
+        let mut values = vec![("resting", self.resting as isize)];
+        if let Some(falling) = self.falling {
+            values.extend([("x", falling.x), ("y", falling.y)]);
+        }
+        values
+    }
+
+    fn entities(&self) -> Vec<String> {
+        match self.falling {
+            Some(falling) => vec![format!("unit {} falling at {},{}", self.resting + 1, falling.x, falling.y)],
+            None if self.with_floor => vec!["the source is blocked".to_string()],
+            None => vec![format!("unit {} fell into the abyss", self.resting + 1)],
+        }
+    }
+}
+
+struct RockLine { from: Point, to: Point }
+
+impl Event for RockLine {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for RockLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rock from {},{} to {},{}", self.from.x, self.from.y, self.to.x, self.to.y)
+    }
+}
+
+struct SandSettled { unit: usize, at: Point }
+
+impl Event for SandSettled {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for SandSettled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sand unit {} comes to rest at {},{}", self.unit, self.at.x, self.at.y)
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use std::str::FromStr;
+use crate::{Answer, Part, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::trace::{self, Event, Level};
+
+
+const ROW: Param = Param { name: "row", default: "2000000", about: "the row part one counts the positions of" };
+const RANGE: Param = Param { name: "range", default: "4000000", about: "the largest coordinate of the distress beacon" };
+
+// this solution hat not been "cleaned up" and shows the dirty truth
+pub struct Day15 {
+    sensors: Vec<Sensor>,
+    puzzle_row: isize,
+    puzzle_range: isize,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let sensors = parse::lines(input, Sensor::from_str)?;
+        Ok(Day15 { sensors, puzzle_row: ROW.default_value(), puzzle_range: RANGE.default_value() })
+    }
+
+    fn params() -> &'static [Param] {
+        &[ROW, RANGE]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.puzzle_row = params.value(&ROW)?;
+        self.puzzle_range = params.value(&RANGE)?;
+        // the real input covers the corner, so a range of 0 leaves nothing to find
+        if self.puzzle_range < 1 {
+            return Err(params.reject(&RANGE, "a coordinate of at least 1"));
+        }
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        let relevant_sensors = self.sensors.iter().collect_vec();
+        empty_space_at_row(&relevant_sensors, self.puzzle_row).len().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.solve_until(Part::Two, &Token::none()).unwrap()
+    }
+
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        token.check()?;
+        if part == Part::One {
+            return Ok(self.part_one());
+        }
+        let relevant_sensors = self.sensors.iter().collect_vec();
+        let puzzle_range = self.puzzle_range;
+
+        let row = first_uncovered("row", puzzle_range, token, |i| is_row_covered(&relevant_sensors, i, puzzle_range))?;
+        let col = first_uncovered("column", puzzle_range, token, |i| is_col_covered(&relevant_sensors, i, puzzle_range))?;
+
+        Ok((col * 4000000 + row).into())
+    }
+}
+
+
+/// The first row or column up to `limit` that `is_covered` says is not, looking at the token every 10000 lines.
+fn first_uncovered(line: &'static str, limit: isize, token: &Token, is_covered: impl Fn(isize) -> bool) -> Result<isize, Cancelled> {
+    for i in 0..limit+1 {
+        if i% 10000 ==0 {
+            trace::emit(|| Scanning { line, at: i });
+            token.check()?;
+        }
+        if !is_covered(i) {
+            trace::emit(|| Uncovered { line, at: i });
+            return Ok(i);
+        }
+    }
+    panic!("every {} up to {} is covered", line, limit)
+}
+
+fn empty_space_at_row(sensors: &[&Sensor], row: isize) -> HashSet<(isize, isize)> {
+
+    sensors.iter().flat_map(|s|s.empty_space_at_row(row))
+        .collect::<HashSet<_>>()
+}
+
+fn is_row_covered(sensors: &[&Sensor], row: isize, limit: isize) -> bool {
+    sensors.iter()
+        .filter_map(|s|s.covered_range_at_row(row))
+        .filter_map(|r| within_limit(r, limit))
+        .map(Some)
+        .sorted()
+        .reduce(|a,b| {
+            match (a, b) {
+                (None, _) => None,
+                (_, None) => None,
+                (Some(a), Some(b)) if b.0 > a.1 && a.1.abs_diff(b.0) > 1 => None,
+                (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.max(b.1)))
+            }
+        })
+        .flatten().map(|r|r.0 <= 0 && r.1 >= limit)
+        .unwrap_or(false)
+}
+
+fn is_col_covered(sensors: &[&Sensor], col: isize, limit: isize) -> bool {
+    sensors.iter()
+        .filter_map(|s|s.covered_range_at_col(col))
+        .filter_map(|r| within_limit(r, limit))
+        .map(Some)
+        .sorted()
+        .reduce(|a,b| {
+            match (a, b) {
+                (None, _) => None,
+                (_, None) => None,
+                (Some(a), Some(b)) if b.0 > a.1 && a.1.abs_diff(b.0) > 1 => None,
+                (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.max(b.1)))
+            }
+        })
+        .flatten().map(|r|r.0 <= 0 && r.1 >= limit)
+        .unwrap_or(false)
+}
+
+/// The part of `range` between 0 and `limit`, so gaps outside of it are not seen.
+fn within_limit(range: (isize, isize), limit: isize) -> Option<(isize, isize)> {
+    let (from, to) = (range.0.max(0), range.1.min(limit));
+    (from <= to).then_some((from, to))
+}
+
+struct Scanning { line: &'static str, at: isize }
+
+impl Event for Scanning {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Scanning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "scanning {} {}", self.line, self.at)
+    }
+}
+
+struct Uncovered { line: &'static str, at: isize }
+
+impl Event for Uncovered {
+    const LEVEL: Level = Level::Info;
+}
+
+impl Display for Uncovered {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} is not fully covered by the sensors", self.line, self.at)
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    coord: (isize, isize),
+    beacon: (isize, isize)
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinate = |n: usize, axis: &str| -> Result<isize, ParseError> {
+            let expected = format!("`{}=` and a coordinate", axis);
+            let word = parse::word(s, n, &expected)?;
+            let value = word.strip_prefix(axis).and_then(|w| w.strip_prefix('='))
+                .ok_or_else(|| ParseError::new(expected, word).within(s, word))?;
+            parse::number(s, value.trim_end_matches([',', ':']))
+        };
+        Ok(Sensor {
+            coord: (coordinate(2, "x")?, coordinate(3, "y")?),
+            beacon: (coordinate(8, "x")?, coordinate(9, "y")?),
+        })
+    }
+}
+
+impl Sensor {
+
+
+    fn covered_range_at_row(&self, row: isize) -> Option<(isize, isize)> {
+        let total_dist = self.dist_to_beacon();
+        let remaining_x_dist = total_dist - (row - self.coord.1).abs();
+        if remaining_x_dist < 0 {
+                None
+        } else {
+            let a = self.coord.0 -remaining_x_dist;
+            let b = self.coord.0 + remaining_x_dist;
+            Some((a.min(b), a.max(b)))
+        }
+    }
+
+    fn covered_range_at_col(&self, col: isize) -> Option<(isize, isize)> {
+        let total_dist = self.dist_to_beacon();
+        let remaining_y_dist = total_dist - (col - self.coord.0).abs();
+        if remaining_y_dist < 0 {
+            None
+        } else {
+            let a = self.coord.1 - remaining_y_dist;
+            let b = self.coord.1 + remaining_y_dist;
+            Some((a.min(b), a.max(b)))
+        }
+    }
+
+    fn empty_space_at_row(&self, row: isize) -> Vec<(isize, isize)> {
+        let total_dist = self.dist_to_beacon();
+        let remaining_x_dist = total_dist - (row - self.coord.1).abs();
+
+        if remaining_x_dist < 0 {
+            return vec![]
+        }
+        (-remaining_x_dist..remaining_x_dist+1)
+            .map(|i| (self.coord.0 + i, row))
+            .filter(|coord| coord != &self.beacon)
+           .collect_vec()
+    }
+
+    fn dist_to_beacon(&self) -> isize {
+        let (dist_x, dist_y) = self.vec_to_beacon();
+        dist_x.abs() + dist_y.abs()
+    }
+
+    fn vec_to_beacon(&self) -> (isize, isize) {
+        (self.beacon.0 - self.coord.0, self.beacon.1 - self.coord.1)
+    }
+}
+
+impl Display for Sensor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})->({},{})", self.coord.0, self.coord.1, self.beacon.0, self.beacon.1)
+    }
+}
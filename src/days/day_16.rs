@@ -0,0 +1,301 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::{Itertools};
+
+use crate::{Answer, Part, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::search::{self, SearchProblem, Strategy};
+use self::Action::{MoveTo, OpenValve, Wait};
+
+const MINUTES: Param = Param { name: "minutes", default: "30", about: "the minutes until the volcano erupts" };
+const PART_TWO_MINUTES: Param = Param { name: "part_two_minutes", default: "26", about: "the minutes left after teaching the elephant" };
+/// The search takes long enough at the 30 minutes of the puzzle, it grows with every minute more.
+const MAX_MINUTES: usize = 30;
+
+pub struct Day16 {
+    arena: BTreeMap<String, Valve>,
+    minutes: usize,
+    part_two_minutes: usize,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day16 {
+            arena: parse_input(input)?,
+            minutes: MINUTES.default_value(),
+            part_two_minutes: PART_TWO_MINUTES.default_value(),
+        })
+    }
+
+    fn params() -> &'static [Param] {
+        &[MINUTES, PART_TWO_MINUTES]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.minutes = params.value(&MINUTES)?;
+        self.part_two_minutes = params.value(&PART_TWO_MINUTES)?;
+        if !(1..=MAX_MINUTES).contains(&self.minutes) {
+            return Err(params.reject(&MINUTES, format!("between 1 and {} minutes", MAX_MINUTES)));
+        }
+        if !(1..=self.minutes).contains(&self.part_two_minutes) {
+            return Err(params.reject(&PART_TWO_MINUTES, format!("between 1 and the {} minutes until the eruption", self.minutes)));
+        }
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        self.solve_until(Part::One, &Token::none()).unwrap()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.solve_until(Part::Two, &Token::none()).unwrap()
+    }
+
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        let (minutes, skip_elephant) = match part {
+            Part::One => (self.minutes, true),
+            Part::Two => (self.part_two_minutes, false),
+        };
+        let context = Context::new(&self.arena, minutes);
+        let start = State::new(&self.arena["AA"]);
+        solve(start, &context, skip_elephant, token).map(Answer::from)
+    }
+}
+
+#[derive(Clone)]
+struct Context<'a> {
+    arena: &'a BTreeMap<String, Valve>,
+    move_action_map: BTreeMap<&'a Valve, Vec<Action<'a>>>,
+    ignore_valves: BTreeSet<&'a Valve>,
+    relevant_valves: BTreeSet<&'a Valve>,
+    max_minutes: usize,
+}
+
+impl<'a> Context<'a> {
+    fn new(arena: &'a BTreeMap<String, Valve>, max_minutes: usize) -> Self {
+        let move_action_map = build_move_action_map(arena);
+        let (relevant_valves, ignore_valves) = arena.values().partition(|v| v.flow_rate > 0);
+
+        Context {
+            arena,
+            move_action_map,
+            ignore_valves,
+            relevant_valves,
+            max_minutes,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct State<'a> {
+    human_position: &'a Valve,
+    elephant_position: &'a Valve,
+    open_valves: BTreeSet<&'a Valve>,
+    after_minute: usize,
+    pressure_rpm: usize,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum Action<'a> {
+    OpenValve(&'a Valve),
+    MoveTo(&'a Valve),
+    Wait,
+}
+
+impl<'a> State<'a> {
+    fn new(start_valve: &'a Valve) -> Self {
+        Self {
+            human_position: start_valve,
+            elephant_position: start_valve,
+            open_valves: Default::default(),
+            after_minute: 0,
+            pressure_rpm: 0,
+        }
+    }
+
+    fn possible_actions(&self, context: &'a Context<'a>, skip_elephant: bool) -> Vec<(Action<'a>, Action<'a>)> {
+        if self.after_minute == context.max_minutes {
+            return vec![];
+        }
+
+        let all_valves_open = self.open_valves.len() == context.arena.len() - context.ignore_valves.len();
+        if all_valves_open {
+            return vec![(Wait, Wait)];
+        }
+
+        let mut human_moves = context.move_action_map.get(&self.human_position).unwrap().iter().cloned().collect_vec();
+        if !self.open_valves.contains(&self.human_position) && !context.ignore_valves.contains(&self.human_position) {
+            human_moves.push(OpenValve(self.human_position))
+        }
+
+        let mut elephant_moves = vec![];
+        if skip_elephant {
+            elephant_moves.push(Wait);
+        } else {
+            elephant_moves.extend(context.move_action_map.get(&self.elephant_position).unwrap().iter().cloned());
+            if !self.open_valves.contains(&self.elephant_position) && !context.ignore_valves.contains(&self.elephant_position) {
+                elephant_moves.push(OpenValve(self.elephant_position))
+            }
+        };
+
+        human_moves.iter().flat_map(|human| elephant_moves.iter().map(|elephant| (human.clone(), elephant.clone())))
+            .filter(|tuple| !matches!(tuple, (OpenValve(h), OpenValve(e)) if h == e))
+            .collect_vec()
+    }
+
+    fn move_elephant_to(mut self, v: &'a Valve) -> Self {
+        self.elephant_position = v;
+        self
+    }
+    fn move_human_to(mut self, v: &'a Valve) -> Self {
+        self.human_position = v;
+        self
+    }
+    fn open_valve(mut self, v: &'a Valve) -> Self {
+        self.open_valves.insert(v);
+        self.pressure_rpm += v.flow_rate;
+        self
+    }
+    fn add_minute(mut self) -> Self {
+        self.after_minute += 1;
+        self
+    }
+    fn fast_forward(mut self, context: &Context) -> Self {
+        self.after_minute = context.max_minutes;
+        self
+    }
+
+    fn successors(&self, context: &'a Context<'a>, skip_elephant: bool) -> Vec<State<'a>> {
+        self.possible_actions(context, skip_elephant)
+            .into_iter()
+            .map(|actions| {
+                match actions {
+                    (OpenValve(h), OpenValve(e)) =>
+                        self.clone().open_valve(h).open_valve(e).add_minute(),
+                    (MoveTo(h), MoveTo(e)) =>
+                        self.clone().move_human_to(h).move_elephant_to(e).add_minute(),
+                    (Wait, Wait) =>
+                        self.clone().fast_forward(context),
+                    (OpenValve(h), MoveTo(e)) =>
+                        self.clone().open_valve(h).move_elephant_to(e).add_minute(),
+                    (MoveTo(h), OpenValve(e)) =>
+                        self.clone().move_human_to(h).open_valve(e).add_minute(),
+                    (OpenValve(v), Wait) =>
+                        self.clone().open_valve(v).add_minute(),
+                    (MoveTo(v), Wait) =>
+                        self.clone().move_human_to(v).add_minute(),
+                    (Wait, OpenValve(v)) =>
+                        self.clone().open_valve(v).add_minute(),
+                    (Wait, MoveTo(v)) =>
+                        self.clone().move_elephant_to(v).add_minute()
+                }
+            })
+            .collect()
+    }
+
+}
+
+
+fn build_move_action_map(arena: &BTreeMap<String, Valve>) -> BTreeMap<&Valve, Vec<Action<'_>>> {
+    arena.values().map(|v| {
+        let actions = v.tunnels_to.iter().map(|label| Action::MoveTo(&arena[label])).collect_vec();
+        (v, actions)
+    }).collect()
+}
+
+type Label = String;
+
+#[derive(Hash, Eq, PartialEq, Debug, Ord, PartialOrd)]
+struct Valve {
+    label: Label,
+    flow_rate: usize,
+    tunnels_to: BTreeSet<String>,
+}
+
+/// Releasing pressure, as minimising the pressure each minute releases less than it would with
+/// every valve open. The minutes after the last valve was opened are taken in one step.
+struct Release<'a> {
+    context: &'a Context<'a>,
+    start: State<'a>,
+    skip_elephant: bool,
+    best_rpm: usize,
+}
+
+impl<'a> SearchProblem for Release<'a> {
+    type State = State<'a>;
+
+    fn start(&self) -> State<'a> {
+        self.start.clone()
+    }
+
+    fn successors(&self, s: &State<'a>) -> Vec<(State<'a>, usize)> {
+        let missed = self.best_rpm - s.pressure_rpm;
+        s.successors(self.context, self.skip_elephant).into_iter()
+            .map(|succ| {
+                let minutes = succ.after_minute - s.after_minute;
+                (succ, minutes * missed)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, s: &State<'a>) -> bool {
+        s.after_minute == self.context.max_minutes
+    }
+
+    /// Every remaining minute at best opens the largest closed valve for each of the two.
+    fn heuristic(&self, s: &State<'a>) -> usize {
+        let openers = if self.skip_elephant { 1 } else { 2 };
+        let mut closed = self.context.relevant_valves.difference(&s.open_valves).map(|v| v.flow_rate).sorted().rev();
+        let mut rpm = s.pressure_rpm;
+        let mut missed = 0;
+        for _ in s.after_minute..self.context.max_minutes {
+            missed += self.best_rpm - rpm;
+            rpm += closed.by_ref().take(openers).sum::<usize>();
+        }
+        missed
+    }
+}
+
+fn solve<'a>(start: State<'a>, context: &'a Context<'a>, skip_elephant: bool, token: &Token) -> Result<usize, Cancelled> {
+    let best_rpm = context.arena.values().map(|v| v.flow_rate).sum::<usize>();
+    let release = Release { context, start, skip_elephant, best_rpm };
+    let path = search::search_until(&release, Strategy::AStar, token)?.path.unwrap();
+    Ok(context.max_minutes * best_rpm - path.cost)
+}
+
+fn parse_input(input: &str) -> Result<BTreeMap<String, Valve>, ParseError> {
+    let valves = parse::lines(input, |l| {
+        let label = parse::word(l, 1, "the label of the valve")?.to_string();
+        let rate = l.split_once("rate=")
+            .map(|(_, rest)| rest.split(';').next().unwrap())
+            .ok_or_else(|| ParseError::end_of(l, "`rate=` and the flow rate"))?;
+        let flow_rate = parse::number(l, rate)?;
+        let tunnels_to = l.replace(',', "")
+            .split(' ')
+            .skip_while(|w| !w.contains("valve"))
+            .skip(1).map(|s| s.to_string())
+            .collect::<BTreeSet<_>>();
+        if tunnels_to.is_empty() {
+            return Err(ParseError::end_of(l, "the valves the tunnels lead to"));
+        }
+
+        Ok(Valve {
+            label,
+            flow_rate,
+            tunnels_to,
+        })
+    })?;
+
+    let labels = valves.iter().map(|valve| valve.label.as_str()).collect::<BTreeSet<_>>();
+    if !labels.contains("AA") {
+        return Err(ParseError::end_of(input, "a valve labeled `AA`"));
+    }
+    if let Some((line, unknown)) = input.lines().zip(&valves)
+        .find_map(|(line, valve)| valve.tunnels_to.iter().find(|t| !labels.contains(t.as_str())).map(|t| (line, t))) {
+        let at = line.rfind(unknown.as_str()).unwrap();
+        return Err(ParseError::new("a known valve", unknown.as_str()).within(input, &line[at..]));
+    }
+    Ok(valves.into_iter().map(|valve| (valve.label.clone(), valve)).collect())
+}
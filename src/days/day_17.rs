@@ -0,0 +1,18 @@
+use crate::{Answer, Solution};
+use crate::parse::ParseError;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn parse(_input: &str) -> Result<Self, ParseError> {
+        Ok(Day17)
+    }
+
+    fn part_one(&self) -> Answer {
+        todo!("not yet implemented")
+    }
+
+    fn part_two(&self) -> Answer {
+        todo!("not yet implemented")
+    }
+}
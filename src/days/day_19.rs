@@ -0,0 +1,371 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use itertools::Itertools;
+use rayon::prelude::*;
+use crate::{Answer, Part, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::params::{Param, Params};
+use crate::parse::{self, ParseError};
+use crate::search::{self, SearchProblem, Strategy};
+use crate::trace::{self, Event, Level};
+use self::Action::{BuildRobot, Wait};
+
+const MINUTES: Param = Param { name: "minutes", default: "24", about: "the minutes part one collects geodes for" };
+const PART_TWO_MINUTES: Param = Param { name: "part_two_minutes", default: "32", about: "the minutes part two collects geodes for" };
+const PART_TWO_BLUEPRINTS: Param = Param { name: "part_two_blueprints", default: "3", about: "how many of the first blueprints part two uses" };
+/// The search takes long enough at the 32 minutes of part two, it grows with every minute more.
+const MAX_MINUTES: usize = 32;
+/// A blueprint opens at most 31 + 30 + ... + 1 = 496 geodes in 32 minutes, with one robot built
+/// per minute at best, and 496 to the 7th still fits in a usize. Fewer blueprints in the input are
+/// all taken.
+const MAX_PART_TWO_BLUEPRINTS: usize = 7;
+
+pub struct Day19 {
+    blueprints: Vec<Vec<Robot>>,
+    minutes: usize,
+    part_two_minutes: usize,
+    part_two_blueprints: usize,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let blueprints = parse::lines(input, |line| {
+            let costs = line.split(' ')
+                .filter_map(|word| word.parse::<usize>().ok())
+                .collect_vec();
+            match costs.len() {
+                6 => Ok(build_blueprint(costs.try_into().unwrap())),
+                n => Err(ParseError::end_of(line, format!("6 robot costs, got {}", n))),
+            }
+        })?;
+        Ok(Day19 {
+            blueprints,
+            minutes: MINUTES.default_value(),
+            part_two_minutes: PART_TWO_MINUTES.default_value(),
+            part_two_blueprints: PART_TWO_BLUEPRINTS.default_value(),
+        })
+    }
+
+    fn params() -> &'static [Param] {
+        &[MINUTES, PART_TWO_MINUTES, PART_TWO_BLUEPRINTS]
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<(), ParseError> {
+        self.minutes = params.value(&MINUTES)?;
+        self.part_two_minutes = params.value(&PART_TWO_MINUTES)?;
+        self.part_two_blueprints = params.value(&PART_TWO_BLUEPRINTS)?;
+        for (param, minutes) in [(&MINUTES, self.minutes), (&PART_TWO_MINUTES, self.part_two_minutes)] {
+            if !(1..=MAX_MINUTES).contains(&minutes) {
+                return Err(params.reject(param, format!("between 1 and {} minutes", MAX_MINUTES)));
+            }
+        }
+        if !(1..=MAX_PART_TWO_BLUEPRINTS).contains(&self.part_two_blueprints) {
+            return Err(params.reject(&PART_TWO_BLUEPRINTS, format!("between 1 and {} blueprints", MAX_PART_TWO_BLUEPRINTS)));
+        }
+        Ok(())
+    }
+
+    fn part_one(&self) -> Answer {
+        self.solve_until(Part::One, &Token::none()).unwrap()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.solve_until(Part::Two, &Token::none()).unwrap()
+    }
+
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        match part {
+            Part::One => {
+                let context = self.build_context(self.minutes);
+                let geodes = solve(context, token)?;
+                Ok(geodes.into_iter().map(|(id, geode)| (id + 1) * geode).sum::<usize>().into())
+            }
+            Part::Two => {
+                let context = self.build_context(self.part_two_minutes).with_first_n_blueprints(self.part_two_blueprints);
+                let geodes = solve(context, token)?;
+                let product = geodes.into_iter().try_fold(1usize, |product, (_, geode)| product.checked_mul(geode));
+                Ok(product.expect("the geodes of a few blueprints multiply within a usize").into())
+            }
+        }
+    }
+}
+
+impl Day19 {
+    fn build_context(&self, max_minutes: usize) -> Context {
+        Context {
+            blueprints: self.blueprints.clone(),
+            selected_blueprint: 0,
+            max_minutes,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Context {
+    blueprints: Vec<Vec<Robot>>,
+    selected_blueprint: usize,
+    max_minutes: usize,
+}
+
+impl Context {
+    fn selected_blueprint(&self) -> &Vec<Robot> {
+        &self.blueprints[self.selected_blueprint]
+    }
+
+    fn max_cost(&self) -> Resources {
+        let costs = self.selected_blueprint().iter().map(|r| &r.cost).collect_vec();
+        Resources {
+            ore: costs.iter().map(|c| c.ore).max().unwrap(),
+            clay: costs.iter().map(|c| c.clay).max().unwrap(),
+            obsidian: costs.iter().map(|c| c.obsidian).max().unwrap(),
+            geode: costs.iter().map(|c| c.geode).max().unwrap(),
+        }
+    }
+
+    fn with_selected_blueprint(&self, index: usize) -> Self {
+        Self {
+            selected_blueprint: index,
+            ..self.clone()
+        }
+    }
+
+    fn with_first_n_blueprints(&self, n: usize) -> Self {
+        Self {
+            blueprints: self.blueprints.iter().take(n).cloned().collect(),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct State {
+    rpm: Resources,
+    after_minute: usize,
+    available: Resources,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State { rpm: Resources::from_ore(1), available: Default::default(), after_minute: 0 }
+    }
+}
+
+impl State {
+    fn successors(&self, ctx: &Context) -> Vec<State> {
+        if self.after_minute == ctx.max_minutes {
+            return Vec::default();
+        }
+
+        let useful_robots = ctx.selected_blueprint().iter()
+            .filter(|robot| self.is_useful(robot, ctx))
+            .collect_vec();
+        let mut actions = useful_robots.iter()
+            .filter(|robot| self.available.enough_for(&robot.cost))
+            .map(|robot| Action::BuildRobot(robot))
+            .collect_vec();
+        if actions.len() < useful_robots.len() {
+            actions.push(Wait);
+        }
+
+        actions.iter()
+            .map(|action| {
+                match action {
+                    BuildRobot(r) => {
+                        Self {
+                            available: &(&self.available + &self.rpm) - &r.cost,
+                            rpm: &self.rpm + &r.rpm,
+                            after_minute: self.after_minute + 1,
+                        }
+                    }
+                    Wait => {
+                        Self {
+                            available: &self.available + &self.rpm,
+                            after_minute: self.after_minute + 1,
+                            ..self.clone()
+                        }
+                    }
+                }
+            })
+            .collect_vec()
+    }
+
+    /// Only one robot can be built per minute, so producing more of a resource per minute
+    /// than the most expensive robot costs of it never helps.
+    fn is_useful(&self, robot: &Robot, ctx: &Context) -> bool {
+        let max_cost = ctx.max_cost();
+        robot.rpm.geode > 0
+            || (robot.rpm.ore > 0 && self.rpm.ore < max_cost.ore)
+            || (robot.rpm.clay > 0 && self.rpm.clay < max_cost.clay)
+            || (robot.rpm.obsidian > 0 && self.rpm.obsidian < max_cost.obsidian)
+    }
+}
+
+#[derive(Debug)]
+enum Action<'a> {
+    BuildRobot(&'a Robot),
+    Wait,
+}
+
+#[derive(Clone, PartialOrd, PartialEq, Eq, Hash, Default, Debug)]
+struct Resources {
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+    geode: usize,
+}
+
+impl Resources {
+    fn from_ore(ore: usize) -> Self { Self { ore, ..Default::default() } }
+    fn from_clay(clay: usize) -> Self { Self { clay, ..Default::default() } }
+    fn from_ore_and_clay(ore: usize, clay: usize) -> Self { Self { ore, clay, ..Default::default() } }
+    fn from_obsidian(obsidian: usize) -> Self { Self { obsidian, ..Default::default() } }
+    fn from_ore_and_obsidian(ore: usize, obsidian: usize) -> Self { Self { ore, obsidian, ..Default::default() } }
+    fn from_geode(geode: usize) -> Self { Self { geode, ..Default::default() } }
+    fn enough_for(&self, rhs: &Resources) -> bool {
+        self.ore >= rhs.ore && self.clay >= rhs.clay && self.obsidian >= rhs.obsidian && self.geode >= rhs.geode
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Robot {
+    cost: Resources,
+    rpm: Resources,
+}
+
+impl Robot {
+    fn new_ore_robot(ore_cost: usize) -> Self {
+        Self {
+            cost: Resources::from_ore(ore_cost),
+            rpm: Resources::from_ore(1),
+        }
+    }
+    fn new_clay_robot(ore_cost: usize) -> Self {
+        Self {
+            cost: Resources::from_ore(ore_cost),
+            rpm: Resources::from_clay(1),
+        }
+    }
+    fn new_obsidian_robot(ore_cost: usize, clay_cost: usize) -> Self {
+        Self {
+            cost: Resources::from_ore_and_clay(ore_cost, clay_cost),
+            rpm: Resources::from_obsidian(1),
+        }
+    }
+    fn new_geode_robot(ore_cost: usize, obsidian_cost: usize) -> Self {
+        Self {
+            cost: Resources::from_ore_and_obsidian(ore_cost, obsidian_cost),
+            rpm: Resources::from_geode(1),
+        }
+    }
+}
+
+
+impl Sub for &Resources {
+    type Output = Resources;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Resources {
+            ore: self.ore - rhs.ore,
+            clay: self.clay - rhs.clay,
+            obsidian: self.obsidian - rhs.obsidian,
+            geode: self.geode - rhs.geode,
+        }
+    }
+}
+
+impl Add for &Resources {
+    type Output = Resources;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Resources {
+            ore: self.ore + rhs.ore,
+            clay: self.clay + rhs.clay,
+            obsidian: self.obsidian + rhs.obsidian,
+            geode: self.geode + rhs.geode,
+        }
+    }
+}
+
+/// Opening geodes with the selected blueprint, as minimising the geodes each minute falls short
+/// of `max_minutes`, which no minute can reach with one robot built per minute.
+struct Mining<'a> {
+    ctx: &'a Context,
+}
+
+impl SearchProblem for Mining<'_> {
+    type State = State;
+
+    fn start(&self) -> State {
+        State::default()
+    }
+
+    fn successors(&self, s: &State) -> Vec<(State, usize)> {
+        let missed = self.ctx.max_minutes - s.rpm.geode;
+        s.successors(self.ctx).into_iter().map(|next| (next, missed)).collect()
+    }
+
+    fn is_goal(&self, s: &State) -> bool {
+        s.after_minute == self.ctx.max_minutes
+    }
+
+    /// Pretends ore is free and a robot of every kind can be built each minute, paid from its own
+    /// resource only. Building as early as possible is then the best plan and never worse than
+    /// what the real rules allow.
+    fn heuristic(&self, s: &State) -> usize {
+        let blueprint = self.ctx.selected_blueprint();
+        let (obsidian_robot, geode_robot) = (&blueprint[2].cost, &blueprint[3].cost);
+        let (mut rpm, mut available) = (s.rpm.clone(), s.available.clone());
+        let mut missed = 0;
+        for _ in s.after_minute..self.ctx.max_minutes {
+            missed += self.ctx.max_minutes - rpm.geode;
+            let build_geode = available.obsidian >= geode_robot.obsidian;
+            let build_obsidian = available.clay >= obsidian_robot.clay;
+            available = &available + &rpm;
+            if build_geode {
+                available.obsidian -= geode_robot.obsidian;
+                rpm.geode += 1;
+            }
+            if build_obsidian {
+                available.clay -= obsidian_robot.clay;
+                rpm.obsidian += 1;
+            }
+            rpm.clay += 1;
+        }
+        missed
+    }
+}
+
+/// The index of every blueprint with the most geodes it opens.
+fn solve(ctx: Context, token: &Token) -> Result<Vec<(usize, usize)>, Cancelled> {
+    (0..ctx.blueprints.len()).collect_vec()
+        .into_par_iter()
+        .map(move |i| ctx.with_selected_blueprint(i))
+        .map(|ctx| {
+            let path = search::search_until(&Mining { ctx: &ctx }, Strategy::BranchAndBound, token)?.path.unwrap();
+            Ok((ctx.selected_blueprint, path.goal().available.geode))
+        })
+        .inspect(|solved| if let Ok((id, geodes)) = solved {
+            trace::emit(|| BlueprintSolved { id: id + 1, geodes: *geodes })
+        })
+        .collect()
+}
+
+struct BlueprintSolved { id: usize, geodes: usize }
+
+impl Event for BlueprintSolved {
+    const LEVEL: Level = Level::Info;
+}
+
+impl Display for BlueprintSolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "blueprint {} opens {} geodes", self.id, self.geodes)
+    }
+}
+
+
+fn build_blueprint(cost: [usize; 6]) -> Vec<Robot> {
+    vec![Robot::new_ore_robot(cost[0]),
+         Robot::new_clay_robot(cost[1]),
+         Robot::new_obsidian_robot(cost[2], cost[3]),
+         Robot::new_geode_robot(cost[4], cost[5])]
+}
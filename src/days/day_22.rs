@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use itertools::Itertools;
+use crate::{Answer, Solution};
+use crate::grid::{Direction, Point, SparseGrid, Turn};
+use crate::parse::{self, ParseError, Within};
+use self::Direction::{Down, Left, Right, Up};
+use self::Instruction::Move;
+use self::TileType::{Open, Wall};
+
+pub struct Day22 {
+    world: World,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day22 { world: World::parse(input)? })
+    }
+
+    fn part_one(&self) -> Answer {
+        let mut world = self.world.clone();
+        world.execute_instructions();
+        world.solution_score().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let mut world = self.world.clone();
+        world.grid.fold_to_cube();
+        world.execute_instructions();
+        world.solution_score().into()
+    }
+}
+
+
+#[derive(Clone)]
+enum TileType { Open, Wall }
+
+
+#[derive(Clone, Debug)]
+enum Instruction { Move(usize), Turn(Turn) }
+
+
+#[derive(Clone, Debug)]
+struct Link {
+    to: Point,
+    rotation: usize
+}
+
+#[derive(Clone)]
+struct LinkedTile {
+    links: BTreeMap<Direction, Link>,
+    tile_type: TileType,
+}
+
+
+#[derive(Clone)]
+struct WrappingGrid {
+    cube_size: isize,
+    tiles: SparseGrid<LinkedTile>,
+}
+
+#[derive(Clone)]
+struct World {
+    grid: WrappingGrid,
+    direction: Direction,
+    position: Point,
+    instructions: Vec<Instruction>,
+}
+
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, path) = parse::blocks(input, Ok)?.into_iter().collect_tuple()
+            .ok_or_else(|| ParseError::end_of(input, "the map followed by the path"))?;
+        let grid = WrappingGrid::try_from(map).within(input, map)?;
+        let position = grid.tiles.iter().find(|(_, tile)| matches!(tile.tile_type, Open)).map(|(p, _)| p)
+            .ok_or_else(|| ParseError::end_of(map, "an open tile"))?;
+        let instructions = parse_instructions(path).within(input, path)?;
+
+        let start_dir = Right;
+        Ok(Self {
+            grid,
+            direction: start_dir,
+            position,
+            instructions,
+        })
+    }
+
+    fn execute_instructions(&mut self) {
+        let instructions = self.instructions.clone();
+        instructions.iter().for_each(|i| self.execute(i));
+    }
+
+    fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Move(steps) => self.move_steps(*steps),
+            Instruction::Turn(turn) => self.direction = self.direction.turn(*turn)
+        }
+    }
+
+    fn solution_score(&self) -> usize {
+        (1000 * (self.position.y + 1) + 4 * (self.position.x + 1)) as usize + facing_value(self.direction)
+    }
+
+
+    fn move_steps(&mut self, count: usize) {
+        for _ in 0..count {
+            let new_pos = &self.grid.tiles[self.position].links[&self.direction];
+            if matches!(self.grid.tiles[new_pos.to].tile_type, Wall) {
+                break;
+            }
+            self.position = new_pos.to;
+            if !new_pos.rotation.is_multiple_of(4) {
+                (0..new_pos.rotation).for_each(|_|self.execute(&Instruction::Turn(Turn::Right)));
+            }
+        }
+    }
+}
+
+
+
+impl WrappingGrid {
+    fn fold_to_cube(&mut self) {
+
+        let seams = cube_seam_pairs(self.cube_size);
+
+        seams.into_iter().for_each(|(rotation, a, b)| {
+            let (a_direction, (a_from, a_to)) = a;
+            let (b_direction, (b_from, b_to)) = b;
+
+            let pairs =
+                self.tiles_along_seam(a_from, a_to).into_iter()
+                    .zip_eq(self.tiles_along_seam(b_from, b_to))
+                    .collect_vec();
+
+            pairs.into_iter().for_each(|(a,b)| {
+               self.tiles.get_mut(a).unwrap().links.insert(a_direction, Link{to: b, rotation});
+               self.tiles.get_mut(b).unwrap().links.insert(b_direction, Link{to: a, rotation: 4-rotation});
+            });
+
+
+        });
+
+    }
+
+    fn tiles_along_seam(&self, from: Point, to_excl: Point) -> Vec<Point> {
+        let mut positions = vec![];
+        let mut current = from;
+        while current != to_excl {
+            assert!(self.tiles.contains(current), "invalid tile id {:?}", current);
+            positions.push(current);
+            current = current + (to_excl - current).signum();
+        }
+        positions
+    }
+
+}
+
+/// The next tile in `direction`, wrapping around to the other end of the row or column.
+fn wrapping_step(position: Point, direction: Direction, row: (isize, isize), column: (isize, isize)) -> Point {
+    match direction {
+        Left if position.x == row.0 => Point::new(row.1, position.y),
+        Right if position.x == row.1 => Point::new(row.0, position.y),
+        Up if position.y == column.0 => Point::new(position.x, column.1),
+        Down if position.y == column.1 => Point::new(position.x, column.0),
+        _ => position.step(direction),
+    }
+}
+
+/// The first and last coordinate of the tiles along each line, keyed by the line.
+fn extents(coordinates: impl Iterator<Item = (isize, isize)>) -> BTreeMap<isize, (isize, isize)> {
+    let mut extents = BTreeMap::new();
+    coordinates.for_each(|(line, at)| {
+        extents.entry(line)
+            .and_modify(|(first, last): &mut (isize, isize)| {
+                *first = (*first).min(at);
+                *last = (*last).max(at);
+            })
+            .or_insert((at, at));
+    });
+    extents
+}
+
+impl TryFrom<char> for TileType {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Open),
+            '#' => Ok(Wall),
+            _ => Err(ParseError::new("`.`, `#` or ` `", value)),
+        }
+    }
+}
+
+fn parse_instructions(line: &str) -> Result<Vec<Instruction>, ParseError> {
+    line.char_indices().group_by(|(_, c)| c.is_ascii_digit()).into_iter()
+        .flat_map(|(is_digits, group)| {
+            if is_digits {
+                let digits = group.collect_vec();
+                let (start, end) = (digits[0].0, digits[digits.len() - 1].0 + 1);
+                vec![parse::number(line, &line[start..end]).map(Move)]
+            } else {
+                group.map(|(i, char)| match char {
+                    'L' => Ok(Instruction::Turn(Turn::Left)),
+                    'R' => Ok(Instruction::Turn(Turn::Right)),
+                    _ => Err(ParseError::new("a number of steps, `L` or `R`", char).within(line, &line[i..])),
+                }).collect_vec()
+            }
+        }).collect()
+}
+
+
+
+type CubeSeam = (Direction, (Point, Point));
+
+fn cube_seam_pairs(size: isize) -> Vec<(usize, CubeSeam, CubeSeam)> {
+    let a_1 = (Point::new(size, 0), Point::new(2*size, 0));
+    let a_2 = (Point::new(0, 3*size), Point::new(0, 4*size));
+
+    let b_1 = (Point::new(2*size, 0), Point::new(3*size, 0));
+    let b_2 = (Point::new(0, 4*size-1), Point::new(size, 4*size-1));
+
+    let c_1 = (Point::new(size, 3*size-1), Point::new(2*size, 3*size-1));
+    let c_2 = (Point::new(size-1, 3*size), Point::new(size-1, 4*size));
+
+    let d_1 = (Point::new(3*size-1, 0), Point::new(3*size-1, size));
+    let d_2 = (Point::new(2*size-1, 3*size-1), Point::new(2*size-1, 2*size-1));
+
+    let e_1 = (Point::new(2*size, size-1), Point::new(3*size, size-1));
+    let e_2 = (Point::new(2*size-1, size), Point::new(2*size-1, 2*size));
+
+    let f_1 = (Point::new(size, size), Point::new(size, 2*size));
+    let f_2 = (Point::new(0, 2*size), Point::new(size, 2*size));
+
+    let g_1 = (Point::new(size, 0), Point::new(size, size));
+    let g_2 = (Point::new(0, 3*size-1), Point::new(0, 2*size-1));
+
+    vec![
+        (1, (Up, a_1), (Left, a_2)),
+        (0, (Up, b_1), (Down, b_2)),
+        (1, (Down, c_1), (Right, c_2)),
+        (2, (Right, d_1), (Right, d_2)),
+        (1, (Down, e_1), (Right, e_2)),
+        (3, (Left, f_1), (Up, f_2)),
+        (2, (Left, g_1), (Left, g_2)),
+    ]
+}
+
+/// The facing as counted in the password: right, down, left, up.
+fn facing_value(direction: Direction) -> usize {
+    match direction {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    }
+}
+
+
+
+impl TryFrom<&str> for WrappingGrid {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tile_types = SparseGrid::parse(input, |c| match c {
+            ' ' => Ok(None),
+            c => TileType::try_from(c).map(Some),
+        })?;
+        let cube_size = tile_types.points().counts_by(|p| p.y).into_values().min().unwrap_or_default() as isize;
+        let rows = extents(tile_types.points().map(|p| (p.y, p.x)));
+        let columns = extents(tile_types.points().map(|p| (p.x, p.y)));
+        let tiles = tile_types.iter()
+            .map(|(position, tile_type)| {
+                let tile = LinkedTile {
+                    links: Direction::all().into_iter().map(|d|{
+                        let link = Link{to: wrapping_step(position, d, rows[&position.y], columns[&position.x]), rotation: 0};
+                        (d, link)
+                    }).collect(),
+                    tile_type: tile_type.clone(),
+                };
+                (position, tile)
+            })
+            .collect();
+
+        Ok(WrappingGrid {
+            cube_size,
+            tiles,
+        })
+    }
+}
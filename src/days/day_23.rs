@@ -0,0 +1,179 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{Answer, Part, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::grid::{Direction, Point, SparseGrid, Turn};
+use crate::grid::Direction::{Down, Left, Right, Up};
+use crate::parse::ParseError;
+use crate::simulation::Simulation;
+use crate::visualize::{self, text_frame, Frame};
+
+pub struct Day23 {
+    world: World,
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day23 { world: input.parse::<World>()? })
+    }
+
+    fn part_one(&self) -> Answer {
+        let mut world = self.world.clone();
+        visualize::frame(|| text_frame(&world.to_string()));
+        world.next_n_rounds(10);
+        world.empty_tiles_in_smallest_rect().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.solve_until(Part::Two, &Token::none()).unwrap()
+    }
+
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        if part == Part::One {
+            token.check()?;
+            return Ok(self.part_one());
+        }
+        let mut elves = Elves::new(self.world.clone());
+        visualize::frame(|| elves.snapshot());
+        while elves.step() {
+            token.check()?;
+        }
+        Ok(elves.round.into())
+    }
+
+    fn simulate(&self, _part: Part) -> Option<Box<dyn Simulation + '_>> {
+        Some(Box::new(Elves::new(self.world.clone())))
+    }
+}
+
+/// The elves spreading out a round at a time, until none of them moves.
+struct Elves {
+    world: World,
+    round: usize,
+    settled: bool,
+}
+
+impl Elves {
+    fn new(world: World) -> Self {
+        Elves { world, round: 0, settled: false }
+    }
+}
+
+impl Simulation for Elves {
+    fn step(&mut self) -> bool {
+        if self.settled {
+            return false;
+        }
+        self.settled = !self.world.next_round();
+        self.round += 1;
+        true
+    }
+
+    fn snapshot(&self) -> Frame {
+        text_frame(&self.world.to_string())
+    }
+
+    fn values(&self) -> Vec<(&'static str, isize)> {
+        vec![
+            ("round", self.round as isize),
+            ("elves", self.world.elves.len() as isize),
+            ("empty", self.world.empty_tiles_in_smallest_rect() as isize),
+        ]
+    }
+
+    fn entities(&self) -> Vec<String> {
+        let order = self.world.movement_order.iter().map(|direction| format!("{:?}", direction).to_lowercase()).join(", ");
+        std::iter::once(format!("directions in order: {}", order))
+            .chain(self.world.elves.points().sorted().map(|elf| format!("elf at {},{}", elf.x, elf.y)))
+            .collect()
+    }
+}
+
+/// The tile in `direction` and its two neighbours that an elf checks before proposing to move there.
+fn adjacent_with_diagonals(elf: Point, direction: Direction) -> [Point; 3] {
+    let ahead = elf.step(direction);
+    [ahead.step(direction.turn(Turn::Left)), ahead, ahead.step(direction.turn(Turn::Right))]
+}
+
+#[derive(Clone)]
+struct World {
+    elves: SparseGrid,
+    movement_order: Vec<Direction>,
+}
+
+
+impl World {
+    fn next_round(&mut self) -> bool{
+        let is_free = |p: &Point| !self.elves.contains(*p);
+        let moving_elves = self.elves.points()
+            .filter(|e| !e.neighbours_with_diagonals().iter().all(is_free))
+            .collect_vec();
+        let moves = moving_elves.into_iter()
+            .filter_map(|e| self.movement_order.iter()
+                .filter(|direction| adjacent_with_diagonals(e, **direction).iter().all(is_free))
+                .map(|direction| (e, e.step(*direction)))
+                .next()
+            )
+            .sorted_by_key(|(_, to)| *to)
+            .dedup_by_with_count(|a, b| a.1 == b.1)
+            .filter(|(count, _)| *count == 1)
+            .map(|(_, proposal)| proposal)
+            .collect_vec();
+
+        let any_moves = !moves.is_empty();
+        moves.into_iter().for_each(|(from, to)| {
+            self.elves.remove(from);
+            self.elves.insert(to, ());
+        });
+
+        let direction = self.movement_order.remove(0);
+        self.movement_order.push(direction);
+
+        visualize::frame(|| text_frame(&self.to_string()));
+        any_moves
+    }
+
+    fn next_n_rounds(&mut self, n: usize) {
+        (0..n).for_each(|_| {self.next_round();})
+    }
+
+    fn empty_tiles_in_smallest_rect(&self) -> usize {
+        let (min, max) = self.elves.bounds().unwrap();
+        ((max.x - min.x + 1) * (max.y - min.y + 1)) as usize - self.elves.len()
+    }
+}
+
+impl FromStr for World {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elves = SparseGrid::parse(s, |c| match c {
+            '#' => Ok(Some(())),
+            '.' => Ok(None),
+            other => Err(ParseError::new("`#` or `.`", other)),
+        })?;
+
+        let movement_order = vec![Up, Down, Left, Right];
+        Ok(Self { elves, movement_order })
+    }
+}
+
+impl Display for World {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (min, max) = self.elves.bounds().unwrap();
+        for y in min.y..max.y+1 {
+            for x in min.x..max.x+1 {
+                let pos = Point::new(x,y);
+                if self.elves.contains(pos) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{Answer, Part, Solution};
+use crate::cancel::{Cancelled, Token};
+use crate::grid::{Direction, Grid, Point, SparseGrid};
+use crate::grid::Direction::{Down, Left, Right, Up};
+use crate::parse::ParseError;
+use crate::search::{self, Path, SearchProblem, Strategy};
+use crate::simulation::Simulation;
+use crate::trace::{self, Event, Level};
+use crate::visualize::{self, Frame};
+use self::Action::{Move, Wait};
+
+pub struct Day24 {
+    context: Context,
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day24 { context: input.parse::<Context>()? })
+    }
+
+    fn part_one(&self) -> Answer {
+        solve_1(self.context.clone(), &Token::none()).unwrap().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        solve_2(self.context.clone(), &Token::none()).unwrap().into()
+    }
+
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        match part {
+            Part::One => solve_1(self.context.clone(), token),
+            Part::Two => solve_2(self.context.clone(), token),
+        }.map(Answer::from)
+    }
+
+    fn simulate(&self, part: Part) -> Option<Box<dyn Simulation + '_>> {
+        let trips = match part {
+            Part::One => 1,
+            Part::Two => 3,
+        };
+        let paths = trips_through(&self.context, trips, &Token::none()).unwrap();
+        let states = paths.into_iter().enumerate()
+            .flat_map(|(i, path)| path.states.into_iter().skip((i > 0) as usize))
+            .collect_vec();
+        Some(Box::new(Expedition { context: &self.context, states, minute: 0 }))
+    }
+}
+
+/// The expedition walking the fastest way through the valley a minute at a time.
+struct Expedition<'a> {
+    context: &'a Context,
+    /// The valley in every minute of the way, found before the first step.
+    states: Vec<State>,
+    minute: usize,
+}
+
+impl Simulation for Expedition<'_> {
+    fn step(&mut self) -> bool {
+        let more = self.minute + 1 < self.states.len();
+        self.minute += more as usize;
+        more
+    }
+
+    fn snapshot(&self) -> Frame {
+        state_frame(&self.states[self.minute], self.context)
+    }
+
+    fn values(&self) -> Vec<(&'static str, isize)> {
+        let state = &self.states[self.minute];
+        vec![
+            ("minute", self.minute as isize),
+            ("x", state.expedition.x),
+            ("y", state.expedition.y),
+            ("blizzards", state.blizzards.len() as isize),
+        ]
+    }
+
+    fn entities(&self) -> Vec<String> {
+        let state = &self.states[self.minute];
+        std::iter::once(format!("expedition at {},{}", state.expedition.x, state.expedition.y))
+            .chain(state.blizzards.iter().map(|b| format!("blizzard {} at {},{}", b.direction.arrow(), b.position.x, b.position.y)))
+            .collect()
+    }
+}
+
+enum Action { Move(Direction), Wait }
+
+impl Action {
+    fn all() -> [Action; 5] {
+        [Move(Left), Move(Right), Move(Up), Move(Down), Wait]
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Blizzard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Blizzard {
+    fn with_position(&self, position: Point) -> Self {
+        Blizzard { position, direction: self.direction }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Context {
+    height: isize,
+    width: isize,
+    entry: Point,
+    exit: Point,
+    walls: SparseGrid,
+    initial_blizzards: Vec<Blizzard>,
+}
+
+impl Context {
+    fn is_wall(&self, pos: Point) -> bool {
+        self.walls.contains(pos)
+    }
+
+    fn blizzard_energy_conservation(&self, pos: Point) -> Point {
+        match (pos.x, pos.y) {
+            (0, y) => Point::new(self.width - 2, y),
+            (x, y) if x == self.width - 1 => Point::new(1, y),
+            (x, 0) => Point::new(x, self.height - 2),
+            (x, y) if y == self.height - 1 => Point::new(x, 1),
+            _ => unimplemented!("no energy conservation needed for {:?}", pos)
+        }
+    }
+
+    fn with_swapped_entry_exit(&self) -> Self {
+        Context {
+            entry: self.exit,
+            exit: self.entry,
+            ..self.clone()}
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct State {
+    expedition: Point,
+    blizzard_positions: BTreeSet<Point>,
+    blizzards: Vec<Blizzard>,
+}
+
+
+impl State {
+    fn successors(&self, context: &Context) -> Vec<Self> {
+        let mut template = self.clone();
+        template.move_blizzards(context);
+        Action::all().iter()
+            .filter_map(|action| {
+                match action {
+                    Move(Up) if self.expedition.y == 0 => None,
+                    Move(Down) if self.expedition.y == context.height - 1 => None,
+                    Move(direction) => {
+                        let pos = self.expedition.step(*direction);
+                        if context.is_wall(pos) || template.blizzard_positions.contains(&pos) {
+                            None
+                        } else {
+                            Some(pos)
+                        }
+                    }
+                    Wait => if template.blizzard_positions.contains(&self.expedition) {
+                        None
+                    } else {
+                        Some(self.expedition)
+                    }
+                }
+            })
+            .map(|expedition| template.with_expedition(expedition))
+            .collect_vec()
+    }
+
+    fn move_blizzards(&mut self, context: &Context) {
+        self.blizzards = self.blizzards.iter().map(|b| {
+            let next_pos = b.position.step(b.direction);
+            if context.is_wall(next_pos) {
+                let pos = context.blizzard_energy_conservation(next_pos);
+                b.with_position(pos)
+            } else {
+                b.with_position(next_pos)
+            }
+        }).collect();
+        self.blizzard_positions = self.blizzards.iter()
+            .map(|b| b.position)
+            .collect()
+    }
+
+    fn with_expedition(&self, expedition: Point) -> State {
+        State { expedition, ..self.clone() }
+    }
+
+    fn from_context(context: &Context) -> Self {
+        State {
+            expedition: context.entry,
+            blizzards: context.initial_blizzards.clone(),
+            blizzard_positions: context.initial_blizzards.iter().map(|b| b.position).collect(),
+        }
+    }
+}
+
+
+impl FromStr for Context {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |c| match c {
+            '#' | '.' => Ok(c),
+            c if Direction::from_arrow(c).is_some() => Ok(c),
+            other => Err(ParseError::new("`#`, `.`, `>`, `<`, `v` or `^`", other)),
+        })?;
+        if map.height() == 0 {
+            return Err(ParseError::end_of(s, "the walls of the valley"));
+        }
+        let walls = map.iter().filter(|(_, c)| **c == '#').map(|(p, _)| p).collect::<SparseGrid>();
+        let blizzards = map.iter()
+            .filter_map(|(position, c)| Direction::from_arrow(*c).map(|direction| Blizzard { position, direction }))
+            .collect_vec();
+
+        let (height, width) = (map.height() as isize, map.width() as isize);
+        let gap = |y: isize, expected: &str| (0..width).map(|x| Point::new(x, y)).find(|pos| !walls.contains(*pos))
+            .ok_or_else(|| {
+                let line = s.lines().nth(y as usize).unwrap_or_default();
+                ParseError::new(expected, line).within(s, line)
+            });
+        let entry = gap(0, "a gap in the top wall")?;
+        let exit = gap(height - 1, "a gap in the bottom wall")?;
+        Ok(Context {
+            height,
+            width,
+            entry,
+            exit,
+            walls,
+            initial_blizzards: blizzards,
+        })
+    }
+}
+
+/// The valley with the expedition as `E` and the number of blizzards where they overlap.
+fn state_frame(state: &State, context: &Context) -> Frame {
+    let mut frame = Frame::new(context.width as usize, context.height as usize, '.');
+    for (p, cell) in frame.iter_mut() {
+        if state.expedition == p {
+            *cell = 'E'
+        } else if context.walls.contains(p) {
+            *cell = '#'
+        } else if state.blizzard_positions.contains(&p) {
+            let blizzards = state.blizzards.iter().filter(|b| b.position == p).collect_vec();
+            *cell = if blizzards.len() == 1 {
+                blizzards[0].direction.arrow()
+            } else {
+                char::from_digit(blizzards.len() as u32, 10).unwrap_or('*')
+            }
+        }
+    }
+    frame
+}
+
+/// A walk from the entry to the exit of a context, starting in some state of the blizzards.
+struct Trip<'a> {
+    start: &'a State,
+    context: &'a Context,
+}
+
+impl SearchProblem for Trip<'_> {
+    type State = State;
+
+    fn start(&self) -> State {
+        self.start.clone()
+    }
+
+    fn successors(&self, state: &State) -> Vec<(State, usize)> {
+        state.successors(self.context).into_iter().map(|s| (s, 1)).collect_vec()
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.expedition == self.context.exit
+    }
+
+    fn heuristic(&self, state: &State) -> usize {
+        state.expedition.manhattan(self.context.exit)
+    }
+}
+
+fn solve(initial_state: &State, context: &Context, token: &Token) -> Result<Path<State>, Cancelled> {
+    let path = search::search_until(&Trip { start: initial_state, context }, Strategy::AStar, token)?.path.unwrap();
+    path.states.iter().for_each(|state| visualize::frame(|| state_frame(state, context)));
+    Ok(path)
+}
+
+struct TripFinished { trip: usize, minutes: usize }
+
+impl Event for TripFinished {
+    const LEVEL: Level = Level::Info;
+}
+
+impl Display for TripFinished {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "trip {} takes {} minutes", self.trip, self.minutes)
+    }
+}
+
+/// The fastest ways of `trips` walks through the valley, back and forth, each starting when the one before ended.
+fn trips_through(ctx_to_exit: &Context, trips: usize, token: &Token) -> Result<Vec<Path<State>>, Cancelled> {
+    let ctx_to_entry = ctx_to_exit.with_swapped_entry_exit();
+    let mut paths: Vec<Path<State>> = vec![];
+    for trip in 1..=trips {
+        let context = if trip % 2 == 1 { ctx_to_exit } else { &ctx_to_entry };
+        let path = match paths.last() {
+            Some(before) => solve(before.goal(), context, token)?,
+            None => solve(&State::from_context(ctx_to_exit), context, token)?,
+        };
+        if trips > 1 {
+            trace::emit(|| TripFinished { trip, minutes: path.cost });
+        }
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+fn solve_1(context: Context, token: &Token) -> Result<usize, Cancelled> {
+    Ok(trips_through(&context, 1, token)?.iter().map(|path| path.cost).sum())
+}
+
+fn solve_2(ctx_to_exit: Context, token: &Token) -> Result<usize, Cancelled> {
+    Ok(trips_through(&ctx_to_exit, 3, token)?.iter().map(|path| path.cost).sum())
+}
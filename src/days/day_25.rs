@@ -1,13 +1,34 @@
 use std::fmt::{Display, Formatter};
-use std::{fs, isize};
 use itertools::Itertools;
 use pathfinding::num_traits::ToPrimitive;
+use crate::{Answer, Solution};
+use crate::parse::{self, ParseError};
 
 
-fn main() {
-    let input = fs::read_to_string("input/day_25.txt").unwrap();
-    let sum = input.lines().map(Snafu::from).map(isize::from).sum::<isize>();
-    assert_eq!("122-2=200-0111--=200", Snafu::from(sum as usize).as_snafu())
+pub struct Day25 {
+    numbers: Vec<String>,
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let numbers = parse::lines(input, |line| {
+            match line.char_indices().find(|(_, c)| !"=-012".contains(*c)) {
+                Some((i, c)) => Err(ParseError::new("a SNAFU digit `=`, `-`, `0`, `1` or `2`", c).within(line, &line[i..])),
+                None if line.is_empty() => Err(ParseError::end_of(line, "a SNAFU number")),
+                None => Ok(line.to_string()),
+            }
+        })?;
+        Ok(Day25 { numbers })
+    }
+
+    fn part_one(&self) -> Answer {
+        let sum = self.numbers.iter().map(|n| Snafu::from(n.as_str())).map(isize::from).sum::<isize>();
+        Snafu::from(sum as usize).as_snafu().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        "there is no part two on day 25".into()
+    }
 }
 
 
@@ -93,4 +114,4 @@ impl From<Snafu> for isize {
                 5_usize.pow(i.to_u32().unwrap()) as isize * c
             }).sum::<isize>()
     }
-}
\ No newline at end of file
+}
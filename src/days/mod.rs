@@ -0,0 +1,90 @@
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::solution::{LoadError, Solution};
+
+type Loader = fn(&str, &Params) -> Result<Box<dyn Solution>, LoadError>;
+
+/// A registered day of the calendar and how to turn its input into a solution.
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<Box<dyn Solution>, ParseError>,
+    load: Loader,
+    params: fn() -> &'static [Param],
+}
+
+impl Day {
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solution>, ParseError> {
+        (self.parse)(input)
+    }
+
+    pub fn params(&self) -> &'static [Param] {
+        (self.params)()
+    }
+
+    /// Hands `params` to a solution of this day, rejecting the ones the day does not have.
+    pub fn configure(&self, solution: &mut dyn Solution, params: &Params) -> Result<(), ParseError> {
+        params.check(self.params())?;
+        solution.configure(params)
+    }
+
+    /// A solution of this day for `input` with `params`, rejecting the ones the day does not have.
+    pub fn load(&self, input: &str, params: &Params) -> Result<Box<dyn Solution>, LoadError> {
+        params.check(self.params()).map_err(LoadError::Params)?;
+        (self.load)(input, params)
+    }
+}
+
+fn boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn boxed_load<S: Solution + 'static>(input: &str, params: &Params) -> Result<Box<dyn Solution>, LoadError> {
+    Ok(Box::new(S::load(input, params)?))
+}
+
+macro_rules! days {
+    ($($number:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const ALL: &[Day] = &[
+            $(Day {
+                number: $number,
+                parse: boxed::<$module::$solution>,
+                load: boxed_load::<$module::$solution>,
+                params: <$module::$solution as Solution>::params,
+            }),*
+        ];
+    };
+}
+
+days! {
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    9 => day_09::Day09,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+    16 => day_16::Day16,
+    17 => day_17::Day17,
+    18 => day_18::Day18,
+    19 => day_19::Day19,
+    20 => day_20::Day20,
+    21 => day_21::Day21,
+    22 => day_22::Day22,
+    23 => day_23::Day23,
+    24 => day_24::Day24,
+    25 => day_25::Day25,
+}
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    ALL.iter().find(|d| d.number == number)
+}
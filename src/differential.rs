@@ -0,0 +1,98 @@
+//! Comparing a solver with a slow but obviously correct reference on many small random inputs,
+//! and shrinking any disagreement to an input small enough to debug by hand.
+
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use itertools::Itertools;
+use crate::generate::Rng;
+use crate::runner;
+use crate::Answer;
+
+/// A random puzzle, kept as data so it can be made smaller.
+pub trait Case: Clone {
+    fn input(&self) -> String;
+
+    /// Variants that are a little smaller or simpler, the ones most likely to still fail first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// The answers of both parts, or the message the solver panicked with.
+pub type Outcome = Result<Vec<Answer>, String>;
+
+/// A case on which the solver and the reference disagree, after shrinking.
+#[derive(Clone, Debug)]
+pub struct Mismatch<C> {
+    /// The seed the original case was generated from.
+    pub seed: u64,
+    /// How many times the case was made smaller.
+    pub shrinks: usize,
+    pub case: C,
+    pub expected: Vec<Answer>,
+    pub found: Outcome,
+}
+
+impl<C: Case> Display for Mismatch<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "seed {} shrunk {} times to\n{}", self.seed, self.shrinks, self.case.input().trim_end())?;
+        writeln!(f, "the reference answers {}", self.expected.iter().join(", "))?;
+        match &self.found {
+            Ok(found) => write!(f, "the solver answers {}", found.iter().join(", ")),
+            Err(message) => write!(f, "the solver panics: {}", message),
+        }
+    }
+}
+
+/// Compares `solve` with `reference` on `cases` random cases, returning the first disagreement
+/// shrunk as far as it goes.
+///
+/// The reference returns `None` for a case that breaks a promise of the puzzle, like a unique
+/// answer; such cases are skipped, and so are the smaller variants that break one.
+pub fn check<C: Case>(
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> C,
+    reference: impl Fn(&C) -> Option<Vec<Answer>>,
+    solve: impl Fn(&C) -> Vec<Answer>,
+) -> Result<(), Mismatch<C>> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = find_mismatch(cases, generate, reference, solve);
+    panic::set_hook(hook);
+    result
+}
+
+fn find_mismatch<C: Case>(
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> C,
+    reference: impl Fn(&C) -> Option<Vec<Answer>>,
+    solve: impl Fn(&C) -> Vec<Answer>,
+) -> Result<(), Mismatch<C>> {
+    let outcome = |case: &C| panic::catch_unwind(AssertUnwindSafe(|| solve(case)))
+        .map_err(|payload| runner::panic_message(&*payload));
+    let failure = |case: &C| {
+        let expected = reference(case)?;
+        let found = outcome(case);
+        (found.as_ref() != Ok(&expected)).then_some((expected, found))
+    };
+
+    let (mut checked, mut seed) = (0, 0);
+    while checked < cases {
+        assert!(seed < 100 * (cases as u64 + 1), "only {} of {} random cases keep the promises of the puzzle", checked, cases);
+        let case = generate(&mut Rng::new(seed));
+        seed += 1;
+        let Some(mut expected) = reference(&case) else {
+            continue;
+        };
+        checked += 1;
+        let mut found = outcome(&case);
+        if found.as_ref() == Ok(&expected) {
+            continue;
+        }
+        let (mut case, mut shrinks) = (case, 0);
+        while let Some((smaller, failed)) = case.shrink().into_iter().find_map(|c| failure(&c).map(|f| (c, f))) {
+            (case, shrinks) = (smaller, shrinks + 1);
+            (expected, found) = failed;
+        }
+        return Err(Mismatch { seed: seed - 1, shrinks, case, expected, found });
+    }
+    Ok(())
+}
@@ -0,0 +1,498 @@
+//! Random puzzle inputs of a chosen size, to see how the solutions scale.
+//!
+//! Every implemented day has a [`Generator`] whose output parses and can be solved. The same
+//! day, size and seed always give the same input, so a slow input can be reproduced.
+
+use std::collections::BTreeSet;
+use itertools::Itertools;
+
+/// A small seeded generator (splitmix64), good enough to shuffle puzzle inputs.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `low` up to and including `high`.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// True `percent` out of a hundred times.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Writes random inputs for one day.
+pub struct Generator {
+    pub day: u8,
+    /// What the size counts, as in "1000 elves".
+    pub unit: &'static str,
+    write: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.write)(&mut Rng::new(seed), size)
+    }
+}
+
+pub const ALL: &[Generator] = &[
+    Generator { day: 1, unit: "elves", write: day_01 },
+    Generator { day: 2, unit: "rounds", write: day_02 },
+    Generator { day: 3, unit: "rucksacks", write: day_03 },
+    Generator { day: 4, unit: "pairs", write: day_04 },
+    Generator { day: 5, unit: "moves", write: day_05 },
+    Generator { day: 6, unit: "characters", write: day_06 },
+    Generator { day: 7, unit: "directories", write: day_07 },
+    Generator { day: 8, unit: "trees per side", write: day_08 },
+    Generator { day: 9, unit: "moves", write: day_09 },
+    Generator { day: 10, unit: "instructions", write: day_10 },
+    Generator { day: 11, unit: "monkeys", write: day_11 },
+    Generator { day: 12, unit: "squares per side", write: day_12 },
+    Generator { day: 13, unit: "pairs of packets", write: day_13 },
+    Generator { day: 14, unit: "rock paths", write: day_14 },
+    Generator { day: 15, unit: "sensors", write: day_15 },
+    Generator { day: 16, unit: "valves with a flow", write: day_16 },
+    Generator { day: 19, unit: "blueprints", write: day_19 },
+    Generator { day: 22, unit: "tiles per cube edge", write: day_22 },
+    Generator { day: 23, unit: "elves", write: day_23 },
+    Generator { day: 24, unit: "blizzards", write: day_24 },
+    Generator { day: 25, unit: "numbers", write: day_25 },
+];
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    ALL.iter().find(|g| g.day == day)
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn letter(rng: &mut Rng, among: usize) -> char {
+    LETTERS[rng.below(among)] as char
+}
+
+fn day_01(rng: &mut Rng, elves: usize) -> String {
+    (0..elves.max(3))
+        .map(|_| lines((0..rng.between(1, 15)).map(|_| rng.between(1000, 70000).to_string())))
+        .join("\n")
+}
+
+fn day_02(rng: &mut Rng, rounds: usize) -> String {
+    lines((0..rounds.max(1)).map(|_| format!("{} {}", *rng.pick(b"ABC") as char, *rng.pick(b"XYZ") as char)))
+}
+
+/// The rucksacks of a group share only the badge, the halves of a rucksack only one item.
+fn day_03(rng: &mut Rng, rucksacks: usize) -> String {
+    let rucksacks_of = |rng: &mut Rng| {
+        let mut items = LETTERS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        items[1..].chunks(17).map(|pool| {
+            let half = rng.between(4, 16);
+            let mut left = [pool[0], badge].into_iter()
+                .chain((2..half).map(|_| pool[rng.between(1, 8)]))
+                .collect_vec();
+            let mut right = [pool[0]].into_iter()
+                .chain((1..half).map(|_| pool[rng.between(9, 16)]))
+                .collect_vec();
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            left.into_iter().chain(right).map(char::from).collect::<String>()
+        }).collect_vec()
+    };
+    lines((0..rucksacks.max(3).div_ceil(3)).flat_map(|_| rucksacks_of(rng)))
+}
+
+fn day_04(rng: &mut Rng, pairs: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.between(1, 99);
+        format!("{}-{}", start, rng.between(start, 99))
+    };
+    lines((0..pairs.max(1)).map(|_| format!("{},{}", range(rng), range(rng))))
+}
+
+/// Nine stacks, none of them ever moved empty.
+fn day_05(rng: &mut Rng, moves: usize) -> String {
+    let mut heights = (0..9).map(|_| rng.between(1, 8)).collect_vec();
+    let tallest = *heights.iter().max().unwrap();
+    let mut drawing = (0..tallest).rev()
+        .map(|row| heights.iter()
+            .map(|height| if *height > row { format!("[{}]", letter(rng, 26).to_ascii_uppercase()) } else { "   ".to_string() })
+            .join(" "))
+        .collect_vec();
+    drawing.push((1..=heights.len()).map(|i| format!(" {} ", i)).join(" "));
+
+    let moves = (0..moves.max(1)).map(|_| {
+        let from = loop {
+            let from = rng.below(heights.len());
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.between(1, heights.len() - 1)) % heights.len();
+        let count = rng.between(1, heights[from] - 1);
+        heights[from] -= count;
+        heights[to] += count;
+        format!("move {} from {} to {}", count, from + 1, to + 1)
+    });
+    format!("{}\n{}", lines(drawing), lines(moves))
+}
+
+/// Only the last 14 characters are all different, so the start of message is found at the end.
+fn day_06(rng: &mut Rng, characters: usize) -> String {
+    let mut marker = LETTERS[..14].to_vec();
+    rng.shuffle(&mut marker);
+    let noise = (14..characters.max(14)).map(|_| letter(rng, 13)).collect::<String>();
+    format!("{}{}\n", noise, marker.into_iter().map(char::from).collect::<String>())
+}
+
+/// A random tree whose files are scaled so the disk is full enough for part two to find a
+/// directory to delete.
+fn day_07(rng: &mut Rng, directories: usize) -> String {
+    let directories = directories.max(1);
+    // the root is directory 0
+    let mut children = vec![vec![]; directories + 1];
+    let mut parents = vec![0];
+    for dir in 1..=directories {
+        let parent = rng.below(dir);
+        children[parent].push(dir);
+        parents.push(parent);
+    }
+    // the last directory has a file, so the disk is never empty
+    let mut files = (0..=directories)
+        .map(|dir| (0..rng.between(usize::from(dir == directories), 4)).map(|_| rng.between(1, 400_000)).collect_vec())
+        .collect_vec();
+    let mut sizes = files.iter().map(|files| files.iter().sum::<usize>()).collect_vec();
+    for dir in (1..=directories).rev() {
+        sizes[parents[dir]] += sizes[dir];
+    }
+    let total = sizes[0];
+    let largest = children[0].iter().map(|dir| sizes[*dir]).max().unwrap_or_default();
+
+    // between 40M and 70M in use, and deleting the largest directory frees the 30M needed
+    let low = 40_000_000.0 / total.max(1) as f64;
+    let high = (70_000_000.0 / total.max(1) as f64).min(40_000_000.0 / (total - largest).max(1) as f64);
+    let scale = (low + high) / 2.0;
+    files.iter_mut().flatten().for_each(|size| *size = ((*size as f64 * scale) as usize).max(1));
+
+    let mut out = vec!["$ cd /".to_string()];
+    list_directory(0, &children, &files, &mut out);
+    lines(out)
+}
+
+/// The terminal output of listing `dir` and then each of its subdirectories.
+fn list_directory(dir: usize, children: &[Vec<usize>], files: &[Vec<usize>], out: &mut Vec<String>) {
+    let name = |index: usize| format!("{}{}", LETTERS[index % 26] as char, index / 26);
+    out.push("$ ls".to_string());
+    out.extend(children[dir].iter().map(|child| format!("dir {}", name(*child))));
+    out.extend(files[dir].iter().enumerate().map(|(i, size)| format!("{} {}.txt", size, name(i))));
+    for child in &children[dir] {
+        out.push(format!("$ cd {}", name(*child)));
+        list_directory(*child, children, files, out);
+        out.push("$ cd ..".to_string());
+    }
+}
+
+fn day_08(rng: &mut Rng, side: usize) -> String {
+    lines((0..side.max(1)).map(|_| (0..side.max(1)).map(|_| rng.between(0, 9).to_string()).collect()))
+}
+
+fn day_09(rng: &mut Rng, moves: usize) -> String {
+    lines((0..moves.max(1)).map(|_| format!("{} {}", *rng.pick(b"LRUD") as char, rng.between(1, 20))))
+}
+
+/// At least the 240 cycles of the crt, keeping the sprite mostly on the screen.
+fn day_10(rng: &mut Rng, instructions: usize) -> String {
+    let (mut x, mut cycles) = (1, 0);
+    let mut program = vec![];
+    while program.len() < instructions || cycles < 240 {
+        if rng.chance(30) {
+            program.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let add = rng.between(0, 20) as isize - 10;
+            let add = if (0..40).contains(&(x + add)) { add } else { -add };
+            x += add;
+            program.push(format!("addx {}", add));
+            cycles += 2;
+        }
+    }
+    lines(program)
+}
+
+/// One monkey squares the worry, unless that overflows within the 20 rounds of part one, then
+/// it adds instead, as do the monkeys multiplying too much.
+fn day_11(rng: &mut Rng, monkeys: usize) -> String {
+    #[derive(Clone, Copy)]
+    enum Operation { Add(usize), Mul(usize), Square }
+
+    let count = monkeys.max(3);
+    let mut operations = (0..count)
+        .map(|_| if rng.chance(25) { Operation::Mul(rng.between(2, 19)) } else { Operation::Add(rng.between(1, 8)) })
+        .collect_vec();
+    operations[rng.below(count)] = Operation::Square;
+    let items = (0..count).map(|_| (0..rng.between(1, 8)).map(|_| rng.between(50, 99)).collect_vec()).collect_vec();
+    let divisors = (0..count).map(|_| *rng.pick(&[2, 3, 5, 7, 11, 13, 17, 19, 23])).collect_vec();
+    let targets = (0..count)
+        .map(|monkey| {
+            let if_true = (monkey + rng.between(1, count - 1)) % count;
+            let if_false = loop {
+                let target = (monkey + rng.between(1, count - 1)) % count;
+                if target != if_true {
+                    break target;
+                }
+            };
+            (if_true, if_false)
+        })
+        .collect_vec();
+
+    // the monkey whose operation overflows first
+    let overflowing = |operations: &[Operation]| -> Option<usize> {
+        let mut items = items.clone();
+        for _ in 0..20 {
+            for monkey in 0..count {
+                for worry in std::mem::take(&mut items[monkey]) {
+                    let worry = match operations[monkey] {
+                        Operation::Add(v) => worry.checked_add(v),
+                        Operation::Mul(v) => worry.checked_mul(v),
+                        Operation::Square => worry.checked_mul(worry),
+                    };
+                    let Some(worry) = worry.map(|worry| worry / 3) else {
+                        return Some(monkey);
+                    };
+                    let (if_true, if_false) = targets[monkey];
+                    items[if worry % divisors[monkey] == 0 { if_true } else { if_false }].push(worry);
+                }
+            }
+        }
+        None
+    };
+    while let Some(monkey) = overflowing(&operations) {
+        operations[monkey] = Operation::Add(1);
+    }
+
+    (0..count)
+        .map(|monkey| {
+            let operation = match operations[monkey] {
+                Operation::Add(v) => format!("old + {}", v),
+                Operation::Mul(v) => format!("old * {}", v),
+                Operation::Square => "old * old".to_string(),
+            };
+            format!("Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    monkey, items[monkey].iter().join(", "), operation, divisors[monkey], targets[monkey].0, targets[monkey].1)
+        })
+        .join("\n")
+}
+
+/// The start is on a row that climbs one step at a time to the column of `z` holding the end.
+fn day_12(rng: &mut Rng, side: usize) -> String {
+    let side = side.max(26);
+    let (start, end) = (rng.below(side), rng.below(side));
+    let climb = |x: usize| x * 25 / (side - 1);
+    lines((0..side).map(|y| (0..side)
+        .map(|x| match (x, y) {
+            (0, y) if y == start => 'S',
+            (x, y) if x == side - 1 && y == end => 'E',
+            (x, y) if x == side - 1 || y == start => LETTERS[climb(x)] as char,
+            (x, _) => LETTERS[climb(x).saturating_sub(rng.below(4))] as char,
+        })
+        .collect()))
+}
+
+fn day_13(rng: &mut Rng, pairs: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items = (0..rng.between(0, 4))
+            .map(|_| if depth < 4 && rng.chance(30) { packet(rng, depth + 1) } else { rng.between(0, 10).to_string() })
+            .join(",");
+        format!("[{}]", items)
+    }
+    (0..pairs.max(1)).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))).join("\n")
+}
+
+/// Paths of a few straight lines below the source of the sand.
+fn day_14(rng: &mut Rng, paths: usize) -> String {
+    let spread = 10 + paths as isize;
+    lines((0..paths.max(1)).map(|_| {
+        let mut at = (500 - spread + rng.below(2 * spread as usize + 1) as isize, rng.between(2, 10 + paths) as isize);
+        let mut points = vec![at];
+        let horizontal_first = rng.chance(50);
+        for segment in 0..rng.between(1, 4) {
+            let length = rng.between(1, 8) as isize;
+            let length = if rng.chance(50) { length } else { -length };
+            if (segment % 2 == 0) == horizontal_first {
+                at.0 += length;
+            } else {
+                at.1 = if at.1 + length >= 1 { at.1 + length } else { at.1 - length };
+            }
+            points.push(at);
+        }
+        points.iter().map(|(x, y)| format!("{},{}", x, y)).join(" -> ")
+    }))
+}
+
+/// A grid of sensors covers the square of part two, except for the single spot of the
+/// distress beacon. The sensors around that spot reach up to it, and four more on its
+/// diagonals close the gaps they leave.
+fn day_15(rng: &mut Rng, sensors: usize) -> String {
+    const RANGE: isize = 4_000_000;
+    let per_side = ((sensors.saturating_sub(4) as f64).sqrt().ceil() as isize).max(2);
+    let spacing = (RANGE + per_side - 2) / (per_side - 1);
+    let hidden = (rng.between(0, RANGE as usize) as isize, rng.between(0, RANGE as usize) as isize);
+    let distance = |a: (isize, isize), b: (isize, isize)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+
+    let grid = (0..per_side).cartesian_product(0..per_side)
+        .map(|(i, j)| (i * spacing, j * spacing))
+        .map(|sensor| (sensor, spacing.min(distance(sensor, hidden) - 1)));
+    let diagonal = 2 * spacing;
+    let around = [(1, 1), (1, -1), (-1, 1), (-1, -1)].into_iter()
+        .map(|(dx, dy)| ((hidden.0 + dx * diagonal, hidden.1 + dy * diagonal), 2 * diagonal - 1));
+    let sign = |rng: &mut Rng| if rng.chance(50) { 1 } else { -1 };
+    lines(grid.chain(around).filter(|(_, reach)| *reach >= 1).map(|(sensor, reach)| {
+        let dx = rng.between(0, reach as usize) as isize;
+        let beacon = (sensor.0 + sign(rng) * dx, sensor.1 + sign(rng) * (reach - dx));
+        format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}", sensor.0, sensor.1, beacon.0, beacon.1)
+    }))
+}
+
+/// Three valves without a flow for every one with, as in the puzzle, connected by a random
+/// tree with a few extra tunnels.
+fn day_16(rng: &mut Rng, flowing: usize) -> String {
+    let flowing = flowing.clamp(1, 168);
+    let count = 4 * flowing + 1;
+    let mut labels = (b'A'..=b'Z').cartesian_product(b'A'..=b'Z')
+        .map(|(a, b)| format!("{}{}", a as char, b as char))
+        .filter(|label| label != "AA")
+        .collect_vec();
+    rng.shuffle(&mut labels);
+    labels.truncate(count - 1);
+    labels.insert(0, "AA".to_string());
+
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        tunnels[a].insert(b);
+        tunnels[b].insert(a);
+    };
+    for valve in 1..count {
+        connect(valve, rng.below(valve));
+    }
+    for _ in 0..count / 2 {
+        let (a, b) = (rng.below(count), rng.below(count));
+        if a != b {
+            connect(a, b);
+        }
+    }
+    lines((0..count).map(|valve| {
+        let rate = if (1..=flowing).contains(&valve) { rng.between(1, 25) } else { 0 };
+        let to = tunnels[valve].iter().map(|other| &labels[*other]).join(", ");
+        let tunnels = if tunnels[valve].len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        format!("Valve {} has flow rate={}; {} {}", labels[valve], rate, tunnels, to)
+    }))
+}
+
+fn day_19(rng: &mut Rng, blueprints: usize) -> String {
+    lines((1..=blueprints.max(1)).map(|id| format!(
+        "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+         Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+        id, rng.between(2, 4), rng.between(2, 4), rng.between(2, 4), rng.between(5, 20), rng.between(2, 4), rng.between(7, 20))))
+}
+
+/// The faces laid out like in the puzzle input, which is the net the cube folding expects.
+fn day_22(rng: &mut Rng, edge: usize) -> String {
+    let edge = edge.max(2);
+    let faces = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+    let map = (0..4 * edge).map(|y| {
+        let width = faces.iter().filter(|(_, row)| *row == y / edge).map(|(column, _)| column + 1).max().unwrap();
+        (0..width * edge)
+            .map(|x| match (x, y) {
+                _ if !faces.contains(&(x / edge, y / edge)) => ' ',
+                (x, 0) if x == edge => '.',
+                _ if rng.chance(10) => '#',
+                _ => '.',
+            })
+            .collect::<String>()
+    }).collect_vec();
+    let path = (0..4 * edge)
+        .map(|_| format!("{}{}", *rng.pick(b"LR") as char, rng.between(1, 2 * edge)))
+        .collect::<String>();
+    format!("{}\n{}\n", lines(map), path.trim_start_matches(['L', 'R']))
+}
+
+/// The elves spread over a square twice their number.
+fn day_23(rng: &mut Rng, elves: usize) -> String {
+    let elves = elves.max(1);
+    let side = ((2 * elves) as f64).sqrt().ceil() as usize;
+    let mut cells = (0..side * side).map(|cell| cell < elves).collect_vec();
+    rng.shuffle(&mut cells);
+    lines(cells.chunks(side).map(|row| row.iter().map(|elf| if *elf { '#' } else { '.' }).collect()))
+}
+
+/// A valley four times as wide as high with a third of it covered by blizzards. As in the
+/// puzzle, no blizzard moves up or down in the columns of the entry and the exit.
+fn day_24(rng: &mut Rng, blizzards: usize) -> String {
+    let blizzards = blizzards.max(1);
+    let height = ((3 * blizzards) as f64 / 4.0).sqrt().round().max(3.0) as usize;
+    let width = (3 * blizzards).div_ceil(height).max(3);
+    let mut cells = (0..width * height).map(|cell| cell < blizzards).collect_vec();
+    rng.shuffle(&mut cells);
+    let valley = cells.chunks(width).map(|row| {
+        let inside = row.iter().enumerate()
+            .map(|(x, blizzard)| match blizzard {
+                false => '.',
+                true if x == 0 || x == width - 1 => *rng.pick(b"<>") as char,
+                true => *rng.pick(b"<>^v") as char,
+            })
+            .collect::<String>();
+        format!("#{}#", inside)
+    });
+    let top = format!("#.{}", "#".repeat(width));
+    let bottom = format!("{}.#", "#".repeat(width));
+    lines([top].into_iter().chain(valley).chain([bottom]))
+}
+
+fn day_25(rng: &mut Rng, numbers: usize) -> String {
+    lines((0..numbers.max(1)).map(|_| snafu(rng.between(1, 1_000_000_000_000))))
+}
+
+fn snafu(mut n: usize) -> String {
+    let mut digits = vec![];
+    while n > 0 {
+        let (digit, carry) = match n % 5 {
+            3 => ('=', 1),
+            4 => ('-', 1),
+            d => ((b'0' + d as u8) as char, 0),
+        };
+        digits.push(digit);
+        n = n / 5 + carry;
+    }
+    digits.iter().rev().collect()
+}
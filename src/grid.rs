@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::{Add, Index, IndexMut, Sub};
+use itertools::Itertools;
+use ndarray::{Array2, ArrayView1, Axis};
+use crate::parse::{self, ParseError};
+use self::Direction::{Down, Left, Right, Up};
+
+/// A position on a puzzle map. `x` grows to the right and `y` grows downwards, like the lines of the input.
+///
+/// Points are ordered in reading order, row by row.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The 4-neighbourhood, in the order of [`Direction::all`].
+    pub fn neighbours(self) -> [Point; 4] {
+        Direction::all().map(|d| self.step(d))
+    }
+
+    /// The 8-neighbourhood, clockwise starting at the top left.
+    pub fn neighbours_with_diagonals(self) -> [Point; 8] {
+        [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)]
+            .map(|(x, y)| self + Point::new(x, y))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The unit step towards `self` on each axis, `0` on an axis without distance.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Direction { Up, Right, Down, Left }
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Turn { Left, Right }
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub fn all() -> [Direction; 4] {
+        [Up, Right, Down, Left]
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Up => Point::new(0, -1),
+            Right => Point::new(1, 0),
+            Down => Point::new(0, 1),
+            Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match (self, turn) {
+            (Up, Turn::Left) | (Down, Turn::Right) => Left,
+            (Up, Turn::Right) | (Down, Turn::Left) => Right,
+            (Right, Turn::Left) | (Left, Turn::Right) => Up,
+            (Right, Turn::Right) | (Left, Turn::Left) => Down,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(Turn::Right).turn(Turn::Right)
+    }
+
+    /// Reads `^`, `>`, `v` and `<` as used on the maps.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Up),
+            '>' => Some(Right),
+            'v' => Some(Down),
+            '<' => Some(Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Up => '^',
+            Right => '>',
+            Down => 'v',
+            Left => '<',
+        }
+    }
+}
+
+/// A rectangular map with a value on every point from `(0, 0)` to `(width - 1, height - 1)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid { cells: Array2::from_elem((height, width), fill) }
+    }
+
+    /// Reads a character map, every line being a row of the same width.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Result<T, ParseError>) -> Result<Self, ParseError> {
+        let width = input.lines().next().map(|line| line.chars().count()).unwrap_or_default();
+        let rows = parse::lines(input, |line| {
+            let row = parse_row(line, |c| cell(c).map(Some))?.into_iter().map(|(_, value)| value).collect_vec();
+            match row.len() {
+                len if len < width => Err(ParseError::end_of(line, format!("{} columns", width))),
+                len if len > width => {
+                    let rest = &line[line.char_indices().nth(width).unwrap().0..];
+                    Err(ParseError::new(format!("{} columns", width), rest).within(line, rest))
+                }
+                _ => Ok(row),
+            }
+        })?;
+        let height = rows.len();
+        let cells = Array2::from_shape_vec((height, width), rows.into_iter().flatten().collect()).unwrap();
+        Ok(Grid { cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).and_then(|index| self.cells.get(index))
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).and_then(|index| self.cells.get_mut(index))
+    }
+
+    /// Every point with its value, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.indexed_iter().map(|((y, x), value)| (Point::new(x as isize, y as isize), value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.cells.indexed_iter_mut().map(|((y, x), value)| (Point::new(x as isize, y as isize), value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.iter().map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> ArrayView1<'_, T> {
+        self.cells.index_axis(Axis(0), y)
+    }
+
+    pub fn column(&self, x: usize) -> ArrayView1<'_, T> {
+        self.cells.index_axis(Axis(1), x)
+    }
+
+    fn index_of(&self, point: Point) -> Option<(usize, usize)> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width())?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height())?;
+        Some((y, x))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point).unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+/// A map that only holds values on some points and is unbounded otherwise.
+/// Without a value type it is a set of points.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SparseGrid<T = ()> {
+    cells: BTreeMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid { cells: BTreeMap::new() }
+    }
+
+    /// Reads a character map of any shape, keeping the points `cell` returns a value for.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Result<Option<T>, ParseError>) -> Result<Self, ParseError> {
+        let rows = parse::lines(input, |line| parse_row(line, &mut cell))?;
+        let cells = rows.into_iter().enumerate()
+            .flat_map(|(y, row)| row.into_iter().map(move |(x, value)| (Point::new(x as isize, y as isize), value)))
+            .collect();
+        Ok(SparseGrid { cells })
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every point with a value, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The top left and bottom right corner of the smallest rectangle around all points.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let (min_x, max_x) = self.points().map(|p| p.x).minmax().into_option()?;
+        let (min_y, max_y) = self.points().map(|p| p.y).minmax().into_option()?;
+        Some((Point::new(min_x, min_y), Point::new(max_x, max_y)))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+impl FromIterator<Point> for SparseGrid {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        iter.into_iter().map(|point| (point, ())).collect()
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.cells[&point]
+    }
+}
+
+/// The values `cell` returns for the characters of `line`, with their column.
+fn parse_row<T>(line: &str, mut cell: impl FnMut(char) -> Result<Option<T>, ParseError>) -> Result<Vec<(usize, T)>, ParseError> {
+    line.char_indices().enumerate()
+        .filter_map(|(x, (i, c))| match cell(c) {
+            Ok(value) => value.map(|value| Ok((x, value))),
+            Err(e) => Some(Err(e.within(line, &line[i..]))),
+        })
+        .collect()
+}
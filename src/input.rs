@@ -0,0 +1,232 @@
+//! Finding the puzzle input of a day, independent of the directory the binaries are run from.
+//!
+//! An explicit path (`-` for stdin) is the only candidate when given. Otherwise the input is
+//! looked up in [`DIR_VAR`] and then in the `input` directory of this repository, each as
+//! `day_XX.txt` or `day_XX/input.txt`. Files that can not be a puzzle input are skipped with
+//! the reason, so the error lists every candidate that was tried.
+//!
+//! A day can also have several inputs, like the ones of everybody on a team: [`resolve_all`]
+//! returns `day_XX.txt` together with every `.txt` file in `day_XX/`. Each of them can come with
+//! a `.params` file of the same name holding its [`Params`]. The files among them that are there
+//! but cannot be used are returned too, so a broken input of a teammate is reported rather than
+//! left out.
+
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use crate::params::Params;
+
+/// A directory with inputs that is searched before the one of the repository.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The explicit path that reads the input from stdin.
+pub const STDIN: &str = "-";
+
+/// The checkout the binaries were built from, which holds `input/` and `answers.txt`.
+pub fn repo_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The text of an input and where it was found, with the parameters that came with it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Input {
+    pub path: PathBuf,
+    pub text: String,
+    pub params: Params,
+}
+
+impl Input {
+    /// The path relative to the repository if the input lives in it, as used in the answer registry.
+    pub fn key(&self) -> &Path {
+        key(&self.path)
+    }
+
+    /// Where the parameters of the input are read from, whether that file exists or not.
+    pub fn params_path(&self) -> PathBuf {
+        self.path.with_extension("params")
+    }
+}
+
+/// `path` relative to the repository if it lies in it, see [`Input::key`].
+pub fn key(path: &Path) -> &Path {
+    path.strip_prefix(repo_dir()).unwrap_or(path)
+}
+
+/// The inputs of a day found by [`resolve_all`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Inputs {
+    pub inputs: Vec<Input>,
+    /// The files next to them that are there but could not be used, with the reason.
+    pub skipped: Vec<(PathBuf, Problem)>,
+}
+
+/// Why a candidate was not used.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Problem {
+    Missing,
+    Unreadable(String),
+    NotText,
+    Empty,
+    /// The content is something else, like the name of another file.
+    Malformed(&'static str),
+    /// The `.params` file next to it could not be read.
+    InvalidParams(String),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Missing => write!(f, "does not exist"),
+            Problem::Unreadable(e) => write!(f, "could not be read: {}", e),
+            Problem::NotText => write!(f, "is not UTF-8 text"),
+            Problem::Empty => write!(f, "is empty"),
+            Problem::Malformed(what) => write!(f, "looks like {} instead of a puzzle input", what),
+            Problem::InvalidParams(e) => write!(f, "comes with invalid parameters: {}", e),
+        }
+    }
+}
+
+/// No candidate held a usable input, with every candidate that was tried.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputError {
+    pub day: u8,
+    pub tried: Vec<(PathBuf, Problem)>,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = |path: &Path| match path == Path::new(STDIN) {
+            true => "stdin".to_string(),
+            false => path.display().to_string(),
+        };
+        if let [(path, problem)] = self.tried.as_slice() {
+            return write!(f, "{}: {}", name(path), problem);
+        }
+        write!(f, "no usable input for day {}, tried:", self.day)?;
+        for (path, problem) in &self.tried {
+            write!(f, "\n  {}: {}", name(path), problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The paths the input of `day` is looked for at, in order.
+pub fn candidates(day: u8, explicit: Option<&Path>) -> Vec<PathBuf> {
+    if let Some(path) = explicit {
+        return vec![path.to_path_buf()];
+    }
+    dirs()
+        .flat_map(|dir| [
+            dir.join(format!("day_{:02}.txt", day)),
+            dir.join(format!("day_{:02}", day)).join("input.txt"),
+        ])
+        .collect()
+}
+
+/// Reads the first usable input of `day`, see the [module docs](self) for the order.
+pub fn resolve(day: u8, explicit: Option<&Path>) -> Result<Input, InputError> {
+    let mut tried = vec![];
+    for path in candidates(day, explicit) {
+        match load(&path) {
+            Ok(input) => return Ok(input),
+            Err(problem) => tried.push((path, problem)),
+        }
+    }
+    Err(InputError { day, tried })
+}
+
+/// Reads every input of `day` from the first directory that has any, `day_XX.txt` first and
+/// then the `.txt` files of `day_XX/` by name. The ones that are there but not usable, like an
+/// empty file or one with malformed parameters, are in [`Inputs::skipped`].
+pub fn resolve_all(day: u8) -> Result<Inputs, InputError> {
+    let mut tried = vec![];
+    for dir in dirs() {
+        let mut found = Inputs::default();
+        for path in day_files(&dir, day) {
+            match load(&path) {
+                Ok(input) => found.inputs.push(input),
+                Err(Problem::Missing) => tried.push((path, Problem::Missing)),
+                Err(problem) => {
+                    tried.push((path.clone(), problem.clone()));
+                    found.skipped.push((path, problem));
+                }
+            }
+        }
+        if !found.inputs.is_empty() || !found.skipped.is_empty() {
+            return Ok(found);
+        }
+    }
+    Err(InputError { day, tried })
+}
+
+/// The directories inputs are looked up in, in order.
+fn dirs() -> impl Iterator<Item = PathBuf> {
+    env::var_os(DIR_VAR).map(PathBuf::from).into_iter()
+        .chain(std::iter::once(repo_dir().join("input")))
+}
+
+fn day_files(dir: &Path, day: u8) -> Vec<PathBuf> {
+    let day_dir = dir.join(format!("day_{:02}", day));
+    let mut files = fs::read_dir(&day_dir).into_iter().flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect::<Vec<_>>();
+    files.sort();
+    if files.is_empty() {
+        files.push(day_dir.join("input.txt"));
+    }
+    files.insert(0, dir.join(format!("day_{:02}.txt", day)));
+    files
+}
+
+fn load(path: &Path) -> Result<Input, Problem> {
+    let text = read(path)?;
+    check(&text)?;
+    let mut input = Input { path: path.to_path_buf(), text, params: Params::default() };
+    if path != Path::new(STDIN) {
+        let params_path = input.params_path();
+        input.params = match read(&params_path) {
+            Ok(text) => Params::parse(&text)
+                .map_err(|e| Problem::InvalidParams(e.in_file(&params_path).to_string()))?
+                .in_file(&params_path),
+            Err(Problem::Missing) => Params::default(),
+            Err(problem) => return Err(Problem::InvalidParams(format!("{}: {}", params_path.display(), problem))),
+        };
+    }
+    Ok(input)
+}
+
+/// Rejects text that can not be the input of any day, before a solver has to make sense of it.
+pub fn check(text: &str) -> Result<(), Problem> {
+    let text = text.trim();
+    let start = text.chars().take(16).collect::<String>().to_ascii_lowercase();
+    if text.is_empty() {
+        Err(Problem::Empty)
+    } else if text.contains("Please log in to get your puzzle input") {
+        Err(Problem::Malformed("the login notice of adventofcode.com"))
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err(Problem::Malformed("a web page"))
+    } else if !text.contains(char::is_whitespace) && text.ends_with(".txt") {
+        Err(Problem::Malformed("the name of another file"))
+    } else {
+        Ok(())
+    }
+}
+
+fn read(path: &Path) -> Result<String, Problem> {
+    let result = if path == Path::new(STDIN) {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map(|_| text)
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Problem::Missing,
+        io::ErrorKind::InvalidData => Problem::NotText,
+        _ => Problem::Unreadable(e.to_string()),
+    })
+}
@@ -0,0 +1,21 @@
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod days;
+pub mod differential;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod params;
+pub mod parse;
+pub mod repl;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod trace;
+pub mod visualize;
+
+pub use solution::{Answer, LoadError, Part, Solution};
@@ -0,0 +1,105 @@
+//! Counting the heap allocations of the solvers, to spot the ones that hold on to too much memory.
+//!
+//! The counts are only kept when a binary installs [`CountingAllocator`] as its global allocator,
+//! `aoc` does. They are shared by all threads, so parts solved at the same time see each other's
+//! allocations, which is why `run --all --parallel` does not measure them.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use serde::{Deserialize, Serialize};
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, keeping track of the bytes in use, their peak and the number of allocations.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(by: usize) {
+        let current = CURRENT.fetch_add(by, Ordering::Relaxed) + by;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        COUNTING.store(true, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Whether [`CountingAllocator`] is installed, which is known once it allocated anything.
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// The heap used by one run of a phase.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+    /// The most bytes in use at once, on top of what was in use before.
+    pub peak: usize,
+    /// How many times memory was allocated or reallocated.
+    pub allocations: usize,
+}
+
+/// Calls `f`, with the heap it used if the allocations are counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    let before = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(before),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (result, is_counting().then_some(usage))
+}
+
+/// A number of bytes in B, KiB, MiB or GiB.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let (mut size, mut unit) = (self.0 as f64, 0);
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        match unit {
+            0 => f.pad(&format!("{} B", self.0)),
+            _ => f.pad(&format!("{:.1} {}", size, UNITS[unit])),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Puzzle parameters, like the row day 15 looks at, which differs between the example of the
+//! puzzle text and the real input.
+//!
+//! Every day declares the parameters it has as [`Param`]s with their defaults. An input can
+//! change them in a `.params` file next to it, one `name = value` per line with blank lines and
+//! lines starting with `#` ignored, and the command line can change them once more.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::parse::{self, ParseError, Within};
+
+/// A tunable value of a puzzle.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value of the puzzle text for the real input, in the form it is given in.
+    pub default: &'static str,
+    pub about: &'static str,
+}
+
+impl Param {
+    /// The default as a number or whatever else `T` is.
+    pub fn default_value<T: FromStr>(&self) -> T {
+        self.parse_default(number)
+    }
+
+    /// The default as a comma separated list.
+    pub fn default_list<T: FromStr>(&self) -> Vec<T> {
+        self.parse_default(list)
+    }
+
+    fn parse_default<T>(&self, parse: impl Fn(&str, &str) -> Result<T, ParseError>) -> T {
+        parse(self.default, self.default)
+            .unwrap_or_else(|e| panic!("the default of `{}` is invalid: {}", self.name, e))
+    }
+}
+
+/// The parameters given for one input, from its `.params` file and the command line.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    file: Option<PathBuf>,
+    text: String,
+    /// The names and values in `text`.
+    values: BTreeMap<String, (Range<usize>, Range<usize>)>,
+    /// Values from the command line, which replace the ones in the file.
+    overrides: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn parse(text: &str) -> Result<Params, ParseError> {
+        let mut values = BTreeMap::new();
+        for line in text.lines() {
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+            let (name, value) = content.split_once('=')
+                .ok_or_else(|| ParseError::new("`name = value`", content).within(text, content))?;
+            let (name, value) = (name.trim(), value.trim());
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(ParseError::new("the name of a parameter", name)).within(text, content);
+            }
+            if value.is_empty() {
+                return Err(ParseError::end_of(content, format!("a value for `{}`", name))).within(text, content);
+            }
+            let range = |s: &str| offset_of(text, s)..offset_of(text, s) + s.len();
+            if values.insert(name.to_string(), (range(name), range(value))).is_some() {
+                return Err(ParseError::new(format!("`{}` only once", name), name)).within(text, name);
+            }
+        }
+        Ok(Params { file: None, text: text.to_string(), values, overrides: BTreeMap::new() })
+    }
+
+    /// The file the parameters were read from, which their errors point to.
+    pub fn in_file(self, file: &Path) -> Self {
+        Params { file: Some(file.to_path_buf()), ..self }
+    }
+
+    /// Replaces the value of `name`, as given on the command line.
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.overrides.insert(name.into(), value.into());
+    }
+
+    /// The value of `param` as a number or whatever else `T` is, its default if it was not given.
+    pub fn value<T: FromStr>(&self, param: &Param) -> Result<T, ParseError> {
+        Ok(self.get(param.name, number)?.unwrap_or_else(|| param.default_value()))
+    }
+
+    /// The value of `param` as a comma separated list, its default if it was not given.
+    pub fn list<T: FromStr>(&self, param: &Param) -> Result<Vec<T>, ParseError> {
+        Ok(self.get(param.name, list)?.unwrap_or_else(|| param.default_list()))
+    }
+
+    fn get<T>(&self, name: &str, parse: impl Fn(&str, &str) -> Result<T, ParseError>) -> Result<Option<T>, ParseError> {
+        let for_name = |e: ParseError| ParseError { expected: format!("{} for `{}`", e.expected, name), ..e };
+        if let Some(value) = self.overrides.get(name) {
+            return parse(value, value).map(Some).map_err(|e| ParseError { line: 0, column: 0, ..for_name(e) });
+        }
+        self.values.get(name)
+            .map(|(_, value)| parse(&self.text, &self.text[value.clone()]).map_err(|e| self.locate(for_name(e))))
+            .transpose()
+    }
+
+    /// An error pointing at the value of `param` that was read fine but does not fit, like a list
+    /// of the wrong length.
+    pub fn reject(&self, param: &Param, expected: impl Into<String>) -> ParseError {
+        let expected = format!("{} for `{}`", expected.into(), param.name);
+        if let Some(value) = self.overrides.get(param.name) {
+            return ParseError { line: 0, column: 0, ..ParseError::new(expected, value.as_str()) };
+        }
+        match self.values.get(param.name) {
+            Some((_, value)) => {
+                let value = &self.text[value.clone()];
+                self.locate(ParseError::new(expected, value).within(&self.text, value))
+            }
+            None => ParseError { line: 0, column: 0, ..ParseError::new(expected, param.default) },
+        }
+    }
+
+    /// Rejects the parameters that are not in `declared`, which would silently be ignored otherwise.
+    pub fn check(&self, declared: &[Param]) -> Result<(), ParseError> {
+        let expected = match declared {
+            [] => "no parameters".to_string(),
+            _ => format!("one of {}", declared.iter().map(|param| format!("`{}`", param.name)).join(", ")),
+        };
+        let known = |name: &str| declared.iter().any(|param| param.name == name);
+        if let Some(name) = self.overrides.keys().find(|name| !known(name)) {
+            return Err(ParseError { line: 0, column: 0, ..ParseError::new(expected, name.as_str()) });
+        }
+        match self.values.iter().find(|(name, _)| !known(name)) {
+            Some((_, (name, _))) => {
+                let name = &self.text[name.clone()];
+                Err(self.locate(ParseError::new(expected, name).within(&self.text, name)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.overrides.is_empty()
+    }
+
+    /// The text of the `.params` file, for [`ParseError::diagnostic`].
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn locate(&self, e: ParseError) -> ParseError {
+        match &self.file {
+            Some(file) => e.in_file(file),
+            None => e,
+        }
+    }
+}
+
+fn number<T: FromStr>(s: &str, value: &str) -> Result<T, ParseError> {
+    parse::number(s, value)
+}
+
+fn list<T: FromStr>(s: &str, value: &str) -> Result<Vec<T>, ParseError> {
+    value.split(',').map(|item| parse::number(s, item.trim())).collect()
+}
+
+fn offset_of(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
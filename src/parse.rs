@@ -0,0 +1,152 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Why and where an input could not be parsed.
+///
+/// Line and column are 1-based and relative to the text that was handed to the failing parser.
+/// Callers that parsed a slice of a larger text move the error into place with [`ParseError::within`].
+/// Values that are not part of a text, like command line arguments, have line 0 and no position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    /// The offending text, empty if the input ended too early.
+    pub found: String,
+}
+
+impl ParseError {
+    /// `found` was not what was expected, reported at the start of the parsed text.
+    pub fn new(expected: impl Into<String>, found: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 1,
+            column: 1,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// `s` ended before `expected` showed up.
+    pub fn end_of(s: &str, expected: impl Into<String>) -> Self {
+        let last_line = s.rsplit('\n').next().unwrap_or_default();
+        ParseError {
+            line: s.matches('\n').count() + 1,
+            column: last_line.chars().count() + 1,
+            ..ParseError::new(expected, "")
+        }
+    }
+
+    /// Moves an error that occurred while parsing `inner` to its position in `outer`.
+    /// `inner` has to be a slice of `outer`, otherwise the error is left untouched.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let Some(offset) = offset_of(outer, inner) else {
+            return self;
+        };
+        let before = &outer[..offset];
+        if self.line == 1 {
+            let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+            self.column += before[line_start..].chars().count();
+        }
+        self.line += before.matches('\n').count();
+        self
+    }
+
+    pub fn in_file(self, file: &Path) -> Self {
+        ParseError { file: Some(file.to_path_buf()), ..self }
+    }
+
+    /// The error followed by the offending line of `input` with a marker below the column.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let Some(line) = self.line.checked_sub(1).and_then(|n| input.lines().nth(n)) else {
+            return self.to_string();
+        };
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let marker = format!("{}^", " ".repeat(self.column - 1));
+        format!("{}\n{} |\n{} | {}\n{} | {}", self, gutter, number, line, gutter, marker)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if self.line > 0 {
+            write!(f, "{}:{}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}, ", self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Shorthand for moving the error of a parse result, see [`ParseError::within`].
+pub trait Within<T> {
+    fn within(self, outer: &str, inner: &str) -> Result<T, ParseError>;
+}
+
+impl<T> Within<T> for Result<T, ParseError> {
+    fn within(self, outer: &str, inner: &str) -> Result<T, ParseError> {
+        self.map_err(|e| e.within(outer, inner))
+    }
+}
+
+/// Parses `token`, a slice of `s`, as a number.
+pub fn number<T: FromStr>(s: &str, token: &str) -> Result<T, ParseError> {
+    token.parse::<T>()
+        .map_err(|_| ParseError::new("a number", token).within(s, token))
+}
+
+/// The `n`th space separated word of `s`.
+pub fn word<'a>(s: &'a str, n: usize, expected: &str) -> Result<&'a str, ParseError> {
+    s.split(' ').nth(n)
+        .ok_or_else(|| ParseError::end_of(s, expected))
+}
+
+/// Parses every line of `input` on its own.
+pub fn lines<'a, T>(input: &'a str, mut parse: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .map(|line| parse(line).within(input, line))
+        .collect()
+}
+
+/// Parses every block of lines of `input` that is separated from the others by blank lines.
+pub fn blocks<'a, T>(input: &'a str, mut parse: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    let mut blocks = vec![];
+    let mut start = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some(offset),
+            (true, Some(block_start)) => {
+                blocks.push(&input[block_start..offset]);
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some(block_start) = start {
+        blocks.push(&input[block_start..]);
+    }
+
+    blocks.into_iter()
+        .map(|block| block.trim_end_matches(['\r', '\n']))
+        .map(|block| parse(block).within(input, block))
+        .collect()
+}
+
+fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let offset = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    (offset + inner.len() <= outer.len()).then_some(offset)
+}
@@ -0,0 +1,236 @@
+//! Stepping through a [`Simulation`] by hand, a command per line as listed by `help`.
+//!
+//! Every `step` and `run` gets a fresh [`Token`] from the REPL, so a condition that never comes
+//! true gives up after the time budget instead of hanging.
+
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+use crate::cancel::Token;
+use crate::simulation::Simulation;
+use crate::visualize;
+
+const HELP: &str = "\
+step [n]                        take one or n steps
+run                             run until the simulation is over
+run until <name> <op> <value>   run until a value compares like that, op is one of == != < <= > >=
+show                            draw the current state
+inspect [text]                  list the values and the entities containing text
+reset                           start over
+quit";
+
+/// A simulation being stepped through, with what it takes to start it over.
+pub struct Repl<'a> {
+    start: Box<dyn Fn() -> Box<dyn Simulation + 'a> + 'a>,
+    simulation: Box<dyn Simulation + 'a>,
+    steps: usize,
+    budget: Option<Duration>,
+}
+
+impl<'a> Repl<'a> {
+    /// A REPL on the simulation made by `start`, which `reset` calls again.
+    pub fn new(start: impl Fn() -> Box<dyn Simulation + 'a> + 'a) -> Self {
+        let simulation = start();
+        Repl { start: Box::new(start), simulation, steps: 0, budget: None }
+    }
+
+    /// Gives up on a `step` or `run` taking longer than `budget`.
+    pub fn with_budget(self, budget: Option<Duration>) -> Self {
+        Repl { budget, ..self }
+    }
+
+    /// Reads commands from `input` until it ends or says `quit`, answering to `output`. With
+    /// `prompt` set a `> ` is written before every command, for people typing them.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write, prompt: bool) -> io::Result<()> {
+        let mut lines = input.lines();
+        loop {
+            if prompt {
+                write!(output, "> ")?;
+                output.flush()?;
+            }
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(());
+            };
+            match line.parse::<Command>() {
+                Ok(Command::Quit) => return Ok(()),
+                Ok(command) => self.execute(command, &mut output)?,
+                Err(e) => writeln!(output, "{}", e)?,
+            }
+        }
+    }
+
+    fn execute(&mut self, command: Command, output: &mut impl Write) -> io::Result<()> {
+        match command {
+            Command::Nothing | Command::Quit => Ok(()),
+            Command::Help => writeln!(output, "{}", HELP),
+            Command::Step(n) => {
+                let token = self.token();
+                let mut taken = 0;
+                while taken < n && token.check().is_ok() && self.simulation.step() {
+                    taken += 1;
+                }
+                self.steps += taken;
+                if taken == n {
+                    writeln!(output, "at step {}", self.steps)
+                } else if token.is_cancelled() {
+                    writeln!(output, "gave up at step {}", self.steps)
+                } else {
+                    writeln!(output, "over at step {}", self.steps)
+                }
+            }
+            Command::Run(condition) => {
+                let token = self.token();
+                loop {
+                    if let Some(condition) = &condition {
+                        if condition.holds(self.simulation.as_ref()) {
+                            return writeln!(output, "{} at step {}", condition, self.steps);
+                        }
+                    }
+                    if token.is_cancelled() {
+                        return writeln!(output, "gave up at step {}", self.steps);
+                    }
+                    if !self.simulation.step() {
+                        return match condition {
+                            Some(condition) => writeln!(output, "over at step {} without {}", self.steps, condition),
+                            None => writeln!(output, "over at step {}", self.steps),
+                        };
+                    }
+                    self.steps += 1;
+                }
+            }
+            Command::Show => write!(output, "{}", visualize::frame_text(&self.simulation.snapshot())),
+            Command::Inspect(filter) => {
+                let filter = filter.unwrap_or_default();
+                writeln!(output, "step = {}", self.steps)?;
+                for (name, value) in self.simulation.values() {
+                    if name.contains(&filter) {
+                        writeln!(output, "{} = {}", name, value)?;
+                    }
+                }
+                for entity in self.simulation.entities() {
+                    if entity.contains(&filter) {
+                        writeln!(output, "{}", entity)?;
+                    }
+                }
+                Ok(())
+            }
+            Command::Reset => {
+                self.simulation = (self.start)();
+                self.steps = 0;
+                writeln!(output, "at step 0")
+            }
+        }
+    }
+
+    fn token(&self) -> Token {
+        self.budget.map_or_else(Token::none, Token::with_budget)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Command {
+    /// An empty line.
+    Nothing,
+    Help,
+    Step(usize),
+    Run(Option<Condition>),
+    Show,
+    Inspect(Option<String>),
+    Reset,
+    Quit,
+}
+
+impl std::str::FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split_whitespace().collect::<Vec<_>>();
+        match words.as_slice() {
+            [] => Ok(Command::Nothing),
+            ["help"] => Ok(Command::Help),
+            ["step"] => Ok(Command::Step(1)),
+            ["step", n] => n.parse().map(Command::Step).map_err(|_| format!("expected a number of steps, found `{}`", n)),
+            ["run"] => Ok(Command::Run(None)),
+            ["run", "until", name, op, value] => Ok(Command::Run(Some(Condition {
+                name: name.to_string(),
+                op: op.parse()?,
+                value: value.parse().map_err(|_| format!("expected a number to compare with, found `{}`", value))?,
+            }))),
+            ["run", ..] => Err("expected `run` or `run until <name> <op> <value>`".to_string()),
+            ["show"] => Ok(Command::Show),
+            ["inspect"] => Ok(Command::Inspect(None)),
+            ["inspect", filter] => Ok(Command::Inspect(Some(filter.to_string()))),
+            ["reset"] => Ok(Command::Reset),
+            ["quit" | "exit"] => Ok(Command::Quit),
+            [other, ..] => Err(format!("expected one of `step`, `run`, `show`, `inspect`, `reset`, `help`, `quit`, found `{}`", other)),
+        }
+    }
+}
+
+/// A value of the simulation compared with a number, like `visited >= 10`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Condition {
+    name: String,
+    op: Op,
+    value: isize,
+}
+
+impl Condition {
+    /// Whether the simulation has the value and it compares like this, never for an unknown name.
+    fn holds(&self, simulation: &dyn Simulation) -> bool {
+        simulation.values().into_iter()
+            .find(|(name, _)| *name == self.name)
+            .is_some_and(|(_, value)| self.op.compare(value, self.value))
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.name, self.op, self.value)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Op { Eq, Ne, Lt, Le, Gt, Ge }
+
+impl Op {
+    fn compare(self, a: isize, b: isize) -> bool {
+        match self {
+            Op::Eq => a == b,
+            Op::Ne => a != b,
+            Op::Lt => a < b,
+            Op::Le => a <= b,
+            Op::Gt => a > b,
+            Op::Ge => a >= b,
+        }
+    }
+}
+
+impl std::str::FromStr for Op {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "==" => Ok(Op::Eq),
+            "!=" => Ok(Op::Ne),
+            "<" => Ok(Op::Lt),
+            "<=" => Ok(Op::Le),
+            ">" => Ok(Op::Gt),
+            ">=" => Ok(Op::Ge),
+            other => Err(format!("expected one of `==`, `!=`, `<`, `<=`, `>`, `>=`, found `{}`", other)),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Op::Eq => "==",
+            Op::Ne => "!=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+        })
+    }
+}
@@ -0,0 +1,181 @@
+//! Solving the whole calendar at once, with a panicking or unfinished day reported instead of
+//! taking the others down.
+
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+use rayon::prelude::*;
+use crate::answers::{self, AnswerRegistry};
+use crate::cancel::Token;
+use crate::days::Day;
+use crate::input::{self, Input, Problem};
+use crate::memory::{self, Usage};
+use crate::{Answer, Part};
+
+/// The message of `todo!()`, which the unfinished days panic with.
+const TODO: &str = "not yet implemented";
+
+/// How solving a part went, the same for every command that reports it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Solved to the recorded answer.
+    Ok,
+    /// Solved, but not to the recorded answer.
+    Wrong,
+    /// Solved, with no answer recorded to compare with.
+    Missing,
+    /// The day is still a `todo!()`.
+    Unimplemented,
+    Panicked(String),
+    /// No input was found, or an empty one, see [`input::resolve_all`].
+    NoInput,
+    /// The input or its parameters were found but could not be used or did not parse.
+    InvalidInput(String),
+    /// Solving took longer than the time budget of a part and was given up.
+    Timeout,
+}
+
+impl Status {
+    /// Whether this means a day that used to work got broken.
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Status::Wrong | Status::Panicked(_) | Status::InvalidInput(_))
+    }
+}
+
+impl From<&Problem> for Status {
+    /// How a part of an input that [`input::resolve_all`] skipped went.
+    fn from(problem: &Problem) -> Self {
+        match problem {
+            Problem::Missing | Problem::Empty => Status::NoInput,
+            problem => Status::InvalidInput(problem.to_string()),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Ok => "ok",
+            Status::Wrong => "wrong",
+            Status::Missing => "missing",
+            Status::Unimplemented => "unimplemented",
+            Status::Panicked(_) => "panicked",
+            Status::NoInput => "no input",
+            Status::InvalidInput(_) => "invalid input",
+            Status::Timeout => "timeout",
+        })
+    }
+}
+
+/// The result of solving one part of a day on one of its inputs.
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub day: u8,
+    pub part: Part,
+    /// The input as keyed in the answer registry, if there was one.
+    pub input: Option<PathBuf>,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    /// How long solving took, unless it never got that far.
+    pub time: Option<Duration>,
+    /// The heap solving used, if it finished and the allocations are counted.
+    pub memory: Option<Usage>,
+}
+
+/// Solves both parts of `day` on each of its inputs, comparing the answers with the ones in `registry`.
+///
+/// A part that is still being solved after `budget` is given up as [`Status::Timeout`], as far as its
+/// solver looks at the token of [`Solution::solve_until`](crate::Solution::solve_until).
+pub fn run(day: &Day, registry: &AnswerRegistry, budget: Option<Duration>) -> Vec<PartRun> {
+    run_day(day, registry, budget, true)
+}
+
+/// Like [`run`], measuring the heap of every part only with `measure` set.
+fn run_day(day: &Day, registry: &AnswerRegistry, budget: Option<Duration>, measure: bool) -> Vec<PartRun> {
+    match input::resolve_all(day.number) {
+        Ok(found) => found.inputs.iter()
+            .flat_map(|input| run_input(day, input, registry, budget, measure))
+            .chain(found.skipped.iter().flat_map(|(path, problem)| failed(day, Some(input::key(path)), problem.into())))
+            .collect(),
+        Err(_) => failed(day, None, Status::NoInput),
+    }
+}
+
+/// Both parts of `day` on `input`, which never got to be solved.
+fn failed(day: &Day, input: Option<&Path>, status: Status) -> Vec<PartRun> {
+    Part::all()
+        .map(|part| PartRun { day: day.number, part, input: input.map(Path::to_path_buf), answer: None, status: status.clone(), time: None, memory: None })
+        .to_vec()
+}
+
+fn run_input(day: &Day, input: &Input, registry: &AnswerRegistry, budget: Option<Duration>, measure: bool) -> Vec<PartRun> {
+    let key = input.key().to_path_buf();
+    let solution = match catch(|| day.load(&input.text, &input.params)) {
+        Ok(Ok(solution)) => solution,
+        Ok(Err(e)) => return failed(day, Some(&key), Status::InvalidInput(e.to_string())),
+        Err(status) => return failed(day, Some(&key), status),
+    };
+
+    Part::all().into_iter()
+        .map(|part| {
+            let token = budget.map_or_else(Token::none, Token::with_budget);
+            let start = Instant::now();
+            let result = catch(|| match measure {
+                true => memory::measure(|| solution.solve_until(part, &token)),
+                false => (solution.solve_until(part, &token), None),
+            });
+            let time = Some(start.elapsed());
+            let input = Some(key.clone());
+            match result {
+                Ok((Err(_), _)) => PartRun { day: day.number, part, input, answer: None, status: Status::Timeout, time, memory: None },
+                Ok((Ok(answer), memory)) => {
+                    let status = answers::check(registry.expected(day.number, part, &key), &answer);
+                    PartRun { day: day.number, part, input, answer: Some(answer), status, time, memory }
+                }
+                Err(status) => PartRun { day: day.number, part, input, answer: None, status, time, memory: None },
+            }
+        })
+        .collect()
+}
+
+/// Solves every day in `days`, on all cores if `parallel` is set, ordered by day, input and part.
+///
+/// The heap is counted for the whole process, so the days solved in parallel would see each
+/// other's allocations; their runs have no [`PartRun::memory`] instead. The panic messages of the days end up in their [`Status`] instead of on stderr.
+pub fn run_all(days: &[Day], registry: &AnswerRegistry, parallel: bool, budget: Option<Duration>) -> Vec<PartRun> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let runs = if parallel {
+        days.par_iter().flat_map_iter(|day| run_day(day, registry, budget, false)).collect::<Vec<_>>()
+    } else {
+        days.iter().flat_map(|day| run(day, registry, budget)).collect()
+    };
+    panic::set_hook(hook);
+    runs.into_iter().sorted_by_key(|run| run.day).collect()
+}
+
+/// Runs `f` like [`run_all`] runs the days, with a panic ending up in the [`Status`] instead of on
+/// stderr.
+pub fn catch_quietly<T>(f: impl FnOnce() -> T) -> Result<T, Status> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = catch(f);
+    panic::set_hook(hook);
+    result
+}
+
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Status> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| match panic_message(&*payload) {
+        message if message.starts_with(TODO) => Status::Unimplemented,
+        message => Status::Panicked(message),
+    })
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "a panic without message".to_string())
+}
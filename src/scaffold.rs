@@ -0,0 +1,117 @@
+//! Generating the files of a new day: the solution module with its registration in the `days!`
+//! list, the input and sample files, and a stub in the sample tests.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use itertools::Itertools;
+
+const MODULE: &str = "use crate::{Answer, Solution};
+use crate::parse::ParseError;
+
+pub struct Day{nn};
+
+impl Solution for Day{nn} {
+    fn parse(_input: &str) -> Result<Self, ParseError> {
+        Ok(Day{nn})
+    }
+
+    fn part_one(&self) -> Answer {
+        todo!(\"not yet implemented\")
+    }
+
+    fn part_two(&self) -> Answer {
+        todo!(\"not yet implemented\")
+    }
+}
+";
+
+const SAMPLE_TEST: &str = "#[test]
+#[ignore = \"the sample answers are not filled in yet\"]
+fn day_{nn}() {
+    check::<day_{nn}::Day{nn}>({n}, \"?\", \"?\");
+}
+";
+
+/// Creates and registers day `day` below the repository at `root`, returning every file it
+/// created or changed. Nothing is written if any of the files already exists or the day is
+/// already registered.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("there is no day {} in the calendar", day)));
+    }
+    let fill = |template: &str| template.replace("{nn}", &format!("{:02}", day)).replace("{n}", &day.to_string());
+
+    let created = [
+        (root.join(format!("src/days/day_{:02}.rs", day)), fill(MODULE)),
+        (root.join(format!("input/day_{:02}.txt", day)), String::new()),
+        (root.join(format!("input/day_{:02}_sample.txt", day)), String::new()),
+    ];
+    if let Some((path, _)) = created.iter().find(|(path, _)| path.exists()) {
+        return Err(already_exists(path, "exists"));
+    }
+
+    let registry = root.join("src/days/mod.rs");
+    let registry_content = fs::read_to_string(&registry)?;
+    if registry_content.contains(&format!("=> day_{:02}::", day)) {
+        return Err(already_exists(&registry, &format!("registers day {}", day)));
+    }
+    let registration = format!("    {} => day_{:02}::Day{:02},", day, day, day);
+    let registry_content = insert_in_order(&registry_content, "days! {", "}", day, registration)
+        .ok_or_else(|| invalid(&registry, "has no `days!` list"))?;
+
+    let tests = root.join("tests/samples.rs");
+    let tests_content = fs::read_to_string(&tests)?;
+    if tests_content.contains(&format!("fn day_{:02}(", day)) {
+        return Err(already_exists(&tests, &format!("tests day {}", day)));
+    }
+    let tests_content = insert_test(&tests_content, day, fill(SAMPLE_TEST));
+
+    for (path, content) in &created {
+        fs::write(path, content)?;
+    }
+    fs::write(&registry, registry_content)?;
+    fs::write(&tests, tests_content)?;
+    Ok(created.into_iter().map(|(path, _)| path).chain([registry, tests]).collect())
+}
+
+/// Puts `line` into the lines between `start` and `end` that begin with a day number, before
+/// the first one with a higher number.
+fn insert_in_order(content: &str, start: &str, end: &str, day: u8, line: String) -> Option<String> {
+    let mut lines = content.lines().map(str::to_string).collect_vec();
+    let first = lines.iter().position(|l| l.trim_end().ends_with(start))? + 1;
+    let last = first + lines[first..].iter().position(|l| l.trim() == end)?;
+    let index = (first..last)
+        .find(|&i| leading_number(&lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(last);
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Puts `test` before the first sample test of a later day, or at the end.
+fn insert_test(content: &str, day: u8, test: String) -> String {
+    let blocks = content.split("\n\n").collect_vec();
+    let later = |block: &&str| block.lines()
+        .find_map(|line| line.strip_prefix("fn day_"))
+        .and_then(leading_number)
+        .is_some_and(|n| n > day);
+    match blocks.iter().position(later) {
+        Some(index) => blocks[..index].iter().copied()
+            .chain([test.trim_end()])
+            .chain(blocks[index..].iter().copied())
+            .join("\n\n"),
+        None => format!("{}\n\n{}", content.trim_end(), test),
+    }
+}
+
+fn leading_number(line: &str) -> Option<u8> {
+    line.trim_start().split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+}
+
+fn already_exists(path: &Path, what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already {}, nothing was written", path.display(), what))
+}
+
+fn invalid(path: &Path, what: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{} {}", path.display(), what))
+}
@@ -0,0 +1,297 @@
+//! Shortest path search over the state spaces of the puzzles, with counters to compare strategies.
+//!
+//! A puzzle describes its states through [`SearchProblem`] and picks a [`Strategy`]. Costs are
+//! minimised; puzzles that maximise something phrase it as the amount they fall short of an
+//! upper bound, so every strategy can run on them.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+use crate::cancel::{Cancelled, Token};
+use crate::trace::{self, Event, Level};
+
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    /// The states reachable in one step, with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the closest goal. `0` turns A* into Dijkstra.
+    ///
+    /// Debug builds check the bound along every path A* and branch and bound find.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Strategy {
+    /// Fewest steps first, only optimal if every step costs the same.
+    Bfs,
+    Dijkstra,
+    AStar,
+    /// Depth first, skipping states whose heuristic can not beat the best goal so far, for
+    /// problems without cycles. Its stack holds the unexpanded siblings of every state on the
+    /// current path, so memory grows with the depth times the branching factor rather than with
+    /// the states visited.
+    BranchAndBound,
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Strategy::Bfs => "bfs",
+            Strategy::Dijkstra => "dijkstra",
+            Strategy::AStar => "a*",
+            Strategy::BranchAndBound => "branch and bound",
+        })
+    }
+}
+
+/// How much work a search took.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// The most states waiting to be expanded at once.
+    pub peak_frontier: usize,
+    pub elapsed: Duration,
+}
+
+/// The states from the start to a goal and the cost of the steps between them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S> {
+    pub states: Vec<S>,
+    pub cost: usize,
+}
+
+impl<S> Path<S> {
+    pub fn goal(&self) -> &S {
+        self.states.last().unwrap()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Report<S> {
+    pub strategy: Strategy,
+    /// The cheapest path, or the one with the fewest steps for [`Strategy::Bfs`].
+    pub path: Option<Path<S>>,
+    pub stats: Stats,
+}
+
+/// Searches `problem` for a goal with `strategy`.
+pub fn search<P: SearchProblem>(problem: &P, strategy: Strategy) -> Report<P::State> {
+    search_until(problem, strategy, &Token::none()).expect("a search without a deadline is never cancelled")
+}
+
+/// Like [`search`], but giving up once `token` is cancelled.
+pub fn search_until<P: SearchProblem>(problem: &P, strategy: Strategy, token: &Token) -> Result<Report<P::State>, Cancelled> {
+    let start = Instant::now();
+    let (path, mut stats) = match strategy {
+        Strategy::Bfs => breadth_first(problem, token)?,
+        Strategy::Dijkstra => best_first(problem, |_| 0, token)?,
+        Strategy::AStar => best_first(problem, |state| problem.heuristic(state), token)?,
+        Strategy::BranchAndBound => branch_and_bound(problem, token)?,
+    };
+    stats.elapsed = start.elapsed();
+
+    let path = path.map(|steps| {
+        if cfg!(debug_assertions) && matches!(strategy, Strategy::AStar | Strategy::BranchAndBound) {
+            check_admissible(problem, &steps);
+        }
+        let cost = steps.last().map(|(_, cost)| *cost).unwrap_or_default();
+        Path { states: steps.into_iter().map(|(state, _)| state).collect(), cost }
+    });
+    trace::emit(|| Searched { strategy, found: path.as_ref().map(|p| p.cost), stats });
+    Ok(Report { strategy, path, stats })
+}
+
+/// How many states are expanded between two looks at the token.
+const CHECK_EVERY: usize = 1024;
+
+/// What the strategies return: the path to a goal if there is one, and how much work it took.
+type Outcome<S> = Result<(Option<Steps<S>>, Stats), Cancelled>;
+
+fn check(stats: &Stats, token: &Token) -> Result<(), Cancelled> {
+    match stats.expanded % CHECK_EVERY {
+        0 => token.check(),
+        _ => Ok(()),
+    }
+}
+
+/// The states of a path with the cost of reaching each of them.
+type Steps<S> = Vec<(S, usize)>;
+
+/// A state that was reached, with the node it was reached from and the cost of getting there.
+struct Node<S> {
+    state: Rc<S>,
+    parent: Option<usize>,
+    cost: usize,
+}
+
+/// The nodes reached so far, each state stored once.
+struct Reached<S> {
+    nodes: Vec<Node<S>>,
+    index: HashMap<Rc<S>, usize>,
+}
+
+impl<S: Eq + Hash + Clone> Reached<S> {
+    fn new(start: S) -> Self {
+        let mut reached = Reached { nodes: vec![], index: HashMap::new() };
+        reached.reach(start, None, 0);
+        reached
+    }
+
+    /// Records a path to `state`, returning its node if that path is the cheapest one so far.
+    fn reach(&mut self, state: S, parent: Option<usize>, cost: usize) -> Option<usize> {
+        match self.index.entry(Rc::new(state)) {
+            Entry::Vacant(entry) => {
+                let node = self.nodes.len();
+                self.nodes.push(Node { state: entry.key().clone(), parent, cost });
+                entry.insert(node);
+                Some(node)
+            }
+            Entry::Occupied(entry) => {
+                let node = &mut self.nodes[*entry.get()];
+                (cost < node.cost).then(|| {
+                    node.parent = parent;
+                    node.cost = cost;
+                    *entry.get()
+                })
+            }
+        }
+    }
+
+    fn steps_to(&self, mut node: usize) -> Steps<S> {
+        let mut steps = vec![];
+        loop {
+            let Node { state, parent, cost } = &self.nodes[node];
+            steps.push(((**state).clone(), *cost));
+            match parent {
+                Some(parent) => node = *parent,
+                None => break,
+            }
+        }
+        steps.reverse();
+        steps
+    }
+}
+
+fn breadth_first<P: SearchProblem>(problem: &P, token: &Token) -> Outcome<P::State> {
+    let mut stats = Stats::default();
+    let mut reached = Reached::new(problem.start());
+    let mut frontier = VecDeque::from([0]);
+    while let Some(node) = frontier.pop_front() {
+        let state = reached.nodes[node].state.clone();
+        if problem.is_goal(&state) {
+            return Ok((Some(reached.steps_to(node)), stats));
+        }
+        check(&stats, token)?;
+        stats.expanded += 1;
+        let cost = reached.nodes[node].cost;
+        for (next, step) in problem.successors(&state) {
+            if !reached.index.contains_key(&next) {
+                frontier.extend(reached.reach(next, Some(node), cost + step));
+            }
+        }
+        stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+    }
+    Ok((None, stats))
+}
+
+fn best_first<P: SearchProblem>(problem: &P, heuristic: impl Fn(&P::State) -> usize, token: &Token) -> Outcome<P::State> {
+    let mut stats = Stats::default();
+    let start = problem.start();
+    // ordered by the estimated total cost, the deeper node first on a tie
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), Reverse(0), 0))]);
+    let mut reached = Reached::new(start);
+    while let Some(Reverse((_, Reverse(cost), node))) = frontier.pop() {
+        if cost > reached.nodes[node].cost {
+            continue;
+        }
+        let state = reached.nodes[node].state.clone();
+        if problem.is_goal(&state) {
+            return Ok((Some(reached.steps_to(node)), stats));
+        }
+        check(&stats, token)?;
+        stats.expanded += 1;
+        for (next, step) in problem.successors(&state) {
+            let estimate = cost + step + heuristic(&next);
+            if let Some(next) = reached.reach(next, Some(node), cost + step) {
+                frontier.push(Reverse((estimate, Reverse(cost + step), next)));
+            }
+        }
+        stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+    }
+    Ok((None, stats))
+}
+
+fn branch_and_bound<P: SearchProblem>(problem: &P, token: &Token) -> Outcome<P::State> {
+    let mut stats = Stats::default();
+    let mut best: Option<Steps<P::State>> = None;
+    let mut path: Steps<P::State> = vec![];
+    // states with their depth, the cost of reaching them and the estimated total, the most promising on top
+    let start = problem.start();
+    let estimate = problem.heuristic(&start);
+    let mut frontier = vec![(start, 0, 0, estimate)];
+    while let Some((state, depth, cost, estimate)) = frontier.pop() {
+        if best.as_ref().is_some_and(|best| estimate >= best.last().unwrap().1) {
+            continue;
+        }
+        path.truncate(depth);
+        path.push((state.clone(), cost));
+        if problem.is_goal(&state) {
+            best = Some(path.clone());
+            continue;
+        }
+        check(&stats, token)?;
+        stats.expanded += 1;
+        let successors = problem.successors(&state).into_iter()
+            .map(|(next, step)| {
+                let estimate = cost + step + problem.heuristic(&next);
+                (next, depth + 1, cost + step, estimate)
+            })
+            .sorted_by_key(|(_, _, _, estimate)| Reverse(*estimate));
+        frontier.extend(successors);
+        stats.peak_frontier = stats.peak_frontier.max(frontier.len());
+    }
+    Ok((best, stats))
+}
+
+/// Panics if the heuristic claims more than the cost that is actually left somewhere on `steps`,
+/// which are optimal, so A* could have returned a worse path elsewhere.
+fn check_admissible<P: SearchProblem>(problem: &P, steps: &Steps<P::State>) {
+    let total = steps.last().map(|(_, cost)| *cost).unwrap_or_default();
+    for (depth, (state, cost)) in steps.iter().enumerate() {
+        let estimate = problem.heuristic(state);
+        assert!(estimate <= total - cost,
+                "the heuristic is not admissible: it estimates {} at step {} of a path that costs {} from there",
+                estimate, depth, total - cost);
+    }
+}
+
+struct Searched { strategy: Strategy, found: Option<usize>, stats: Stats }
+
+impl Event for Searched {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Searched {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.found {
+            Some(cost) => write!(f, "{} found a path costing {}", self.strategy, cost)?,
+            None => write!(f, "{} found no path", self.strategy)?,
+        }
+        write!(f, " after expanding {} states, at most {} waiting, in {:.2?}",
+               self.stats.expanded, self.stats.peak_frontier, self.stats.elapsed)
+    }
+}
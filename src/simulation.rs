@@ -0,0 +1,23 @@
+//! The simulations behind the days that move things around step by step, like the rope of day 9
+//! or the elves of day 23, so they can be stepped through by hand with `aoc repl`.
+//!
+//! The solvers of these days run the same simulation to its end, so what the REPL shows is what
+//! the answer is computed from.
+
+use crate::visualize::Frame;
+
+/// A state that changes one step at a time.
+pub trait Simulation {
+    /// Takes the next step, `false` once the simulation is over and nothing changed.
+    fn step(&mut self) -> bool;
+
+    /// The current state as the day draws it.
+    fn snapshot(&self) -> Frame;
+
+    /// Numbers describing the current state, like the register of day 10, which the conditions
+    /// of `run until` refer to by name.
+    fn values(&self) -> Vec<(&'static str, isize)>;
+
+    /// The things being simulated, a line each, like the knots of a rope and where they are.
+    fn entities(&self) -> Vec<String>;
+}
@@ -0,0 +1,129 @@
+use std::fmt::{Display, Formatter};
+use crate::cancel::{Cancelled, Token};
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::simulation::Simulation;
+
+/// The answer to one part of a puzzle, kept in its displayed form so numbers and
+/// strings (like day 5's crate tops or day 10's CRT image) can be handled alike.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+macro_rules! answer_from_display {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer(value.to_string())
+            }
+        })*
+    };
+}
+
+answer_from_display!(usize, isize, u32, u64, i32, i64, String, &str);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part { One, Two }
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("there is no part {}", value)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.number(), f)
+    }
+}
+
+/// Why an input could not be loaded, by the text the error points into.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadError {
+    Input(ParseError),
+    Params(ParseError),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Input(e) | LoadError::Params(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+/// A single day: the input is parsed once and both parts are solved on the parsed form.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self, ParseError> where Self: Sized;
+
+    /// The parameters of the puzzle, which [`Solution::parse`] sets to their defaults.
+    fn params() -> &'static [Param] where Self: Sized {
+        &[]
+    }
+
+    /// Takes over the values of `params`, the defaults for the ones that are not given.
+    fn configure(&mut self, _params: &Params) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// Parses `input` and configures the solution with `params`. Days whose parameters change
+    /// what a valid input is, like the symbols of day 2, read the input with them instead.
+    fn load(input: &str, params: &Params) -> Result<Self, LoadError> where Self: Sized {
+        let mut solution = Self::parse(input).map_err(LoadError::Input)?;
+        solution.configure(params).map_err(LoadError::Params)?;
+        Ok(solution)
+    }
+
+    fn part_one(&self) -> Answer;
+
+    fn part_two(&self) -> Answer;
+
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part_one(),
+            Part::Two => self.part_two(),
+        }
+    }
+
+    /// Like [`Solution::solve`], but giving up once `token` is cancelled. The days that can take
+    /// long check the token while they work, the others only before they start.
+    fn solve_until(&self, part: Part, token: &Token) -> Result<Answer, Cancelled> {
+        token.check()?;
+        Ok(self.solve(part))
+    }
+
+    /// The simulation `part` is solved with, for the days that simulate something step by step.
+    fn simulate(&self, _part: Part) -> Option<Box<dyn Simulation + '_>> {
+        None
+    }
+}
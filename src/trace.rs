@@ -0,0 +1,100 @@
+//! Events solvers report while they run, silent unless a verbosity is selected.
+//!
+//! Solvers define small event types and hand them to [`emit`] as a closure, so an event that
+//! is filtered out costs no more than a load of the current level.
+
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// Selects the verbosity when no `-v` flag is given: `1`/`info` or `2`/`debug`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Level {
+    /// Progress of a solver, a handful of lines per part.
+    Info = 1,
+    /// Every step of a solver.
+    Debug = 2,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+/// Something a solver reports, displayed as a single line.
+pub trait Event: Display {
+    const LEVEL: Level;
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Enables events up to `verbosity` (0 is silent, 1 info, 2 and above debug) or the level of
+/// [`ENV_VAR`] if `verbosity` is 0. Events go to stderr, or to `file` which then also
+/// enables every event unless a level was selected.
+pub fn init(verbosity: u8, file: Option<&Path>) -> io::Result<()> {
+    let mut verbosity = match verbosity {
+        0 => env::var(ENV_VAR).ok().and_then(|level| parse_level(&level)).unwrap_or(0),
+        v => v.min(Level::Debug as u8),
+    };
+    let sink: Box<dyn Write + Send> = match file {
+        Some(path) => {
+            if verbosity == 0 {
+                verbosity = Level::Debug as u8;
+            }
+            Box::new(io::BufWriter::new(File::create(path)?))
+        }
+        None => Box::new(io::stderr()),
+    };
+    *SINK.lock().unwrap() = Some(sink);
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Reports the event built by `event` if its level is enabled.
+pub fn emit<E: Event>(event: impl FnOnce() -> E) {
+    if !enabled(E::LEVEL) {
+        return;
+    }
+    let event = event();
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        let _ = writeln!(sink, "[{} {}] {}", E::LEVEL.name(), source::<E>(), event);
+    }
+}
+
+/// Writes out buffered events, called before the process exits.
+pub fn flush() {
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        let _ = sink.flush();
+    }
+}
+
+fn parse_level(level: &str) -> Option<u8> {
+    match level.trim().to_ascii_lowercase().as_str() {
+        "0" | "off" | "" => Some(0),
+        "1" | "info" => Some(Level::Info as u8),
+        "2" | "debug" => Some(Level::Debug as u8),
+        _ => None,
+    }
+}
+
+/// The module an event type is defined in, like `day_11`.
+fn source<E>() -> &'static str {
+    let path = std::any::type_name::<E>();
+    let path = path.split('<').next().unwrap_or(path);
+    path.rsplit("::").nth(1).unwrap_or(path)
+}
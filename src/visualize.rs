@@ -0,0 +1,248 @@
+//! Snapshots of the simulating days, written as text frames, images or a terminal recording.
+//!
+//! Like [`trace`](crate::trace), solvers hand their frames to [`frame`] as a closure, which is
+//! only called while a [`FrameSink`] is installed with [`start`].
+
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use itertools::Itertools;
+use crate::grid::{Grid, Point};
+
+/// A snapshot of a map, a character per cell.
+pub type Frame = Grid<char>;
+
+/// Builds a frame from the lines of `text`, padding shorter lines with spaces.
+pub fn text_frame(text: &str) -> Frame {
+    let width = text.lines().map(|line| line.chars().count()).max().unwrap_or_default();
+    let mut frame = Frame::new(width, text.lines().count(), ' ');
+    for (y, line) in text.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            frame[Point::new(x as isize, y as isize)] = c;
+        }
+    }
+    frame
+}
+
+/// The rows of `frame` as lines of text.
+pub fn frame_text(frame: &Frame) -> String {
+    (0..frame.height()).map(|y| frame.row(y).iter().collect::<String>() + "\n").collect()
+}
+
+/// Receives the frames of one simulation in order.
+pub trait FrameSink {
+    fn push(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called after the last frame.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// A text file per frame.
+    Ascii,
+    /// A color image per frame.
+    Ppm,
+    /// A grayscale image per frame.
+    Pgm,
+    /// A single asciicast v2 recording, replayed with `asciinema play`.
+    Asciicast,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(Format::Ascii),
+            "ppm" => Ok(Format::Ppm),
+            "pgm" => Ok(Format::Pgm),
+            "asciicast" | "cast" => Ok(Format::Asciicast),
+            other => Err(format!("expected ascii, ppm, pgm or asciicast, found `{}`", other)),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Format::Ascii => "ascii",
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+            Format::Asciicast => "asciicast",
+        })
+    }
+}
+
+/// A sink writing `format` into `dir`, with file names starting with `name`.
+pub fn sink(format: Format, dir: &Path, name: &str) -> io::Result<Box<dyn FrameSink + Send>> {
+    fs::create_dir_all(dir)?;
+    let files = NumberedFiles { dir: dir.to_path_buf(), name: name.to_string(), count: 0 };
+    Ok(match format {
+        Format::Ascii => Box::new(AsciiSink { files }),
+        Format::Ppm => Box::new(ImageSink { files, gray: false }),
+        Format::Pgm => Box::new(ImageSink { files, gray: true }),
+        Format::Asciicast => Box::new(AsciicastSink::new(dir.join(format!("{}.cast", name)))),
+    })
+}
+
+/// `name_0001.ext`, `name_0002.ext`, … in `dir`.
+struct NumberedFiles {
+    dir: PathBuf,
+    name: String,
+    count: usize,
+}
+
+impl NumberedFiles {
+    fn next(&mut self, extension: &str) -> io::Result<BufWriter<File>> {
+        self.count += 1;
+        let path = self.dir.join(format!("{}_{:04}.{}", self.name, self.count, extension));
+        Ok(BufWriter::new(File::create(path)?))
+    }
+}
+
+pub struct AsciiSink {
+    files: NumberedFiles,
+}
+
+impl FrameSink for AsciiSink {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut file = self.files.next("txt")?;
+        file.write_all(frame_text(frame).as_bytes())?;
+        file.flush()
+    }
+}
+
+/// Binary PPM (`P6`) or PGM (`P5`) images, every cell a square of [`ImageSink::SCALE`] pixels.
+pub struct ImageSink {
+    files: NumberedFiles,
+    gray: bool,
+}
+
+impl ImageSink {
+    const SCALE: usize = 4;
+}
+
+impl FrameSink for ImageSink {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        let (magic, extension) = if self.gray { ("P5", "pgm") } else { ("P6", "ppm") };
+        let mut file = self.files.next(extension)?;
+        write!(file, "{}\n{} {}\n255\n", magic, frame.width() * Self::SCALE, frame.height() * Self::SCALE)?;
+        for y in 0..frame.height() {
+            let row = frame.row(y).iter()
+                .flat_map(|c| {
+                    let pixel = if self.gray { vec![brightness(*c)] } else { color(*c).to_vec() };
+                    std::iter::repeat_n(pixel, Self::SCALE).flatten()
+                })
+                .collect_vec();
+            for _ in 0..Self::SCALE {
+                file.write_all(&row)?;
+            }
+        }
+        file.flush()
+    }
+}
+
+/// Colors of the characters without a fixed one.
+const PALETTE: [[u8; 3]; 6] = [[80, 140, 230], [90, 200, 120], [230, 180, 60], [200, 90, 200], [60, 200, 200], [230, 120, 60]];
+
+/// Background, walls and lit pixels have fixed colors, every other character one of [`PALETTE`].
+fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' | '.' | '_' => [16, 16, 32],
+        '#' | '█' => [230, 230, 230],
+        'E' | '@' => [230, 40, 40],
+        c => PALETTE[c as usize % PALETTE.len()],
+    }
+}
+
+fn brightness(c: char) -> u8 {
+    let [r, g, b] = color(c);
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+}
+
+/// An asciicast v2 recording, every frame redrawing the terminal [`AsciicastSink::INTERVAL`]
+/// seconds after the previous one. The header needs the largest frame, so it is written at the end.
+pub struct AsciicastSink {
+    path: PathBuf,
+    width: usize,
+    height: usize,
+    frames: Vec<String>,
+}
+
+impl AsciicastSink {
+    const INTERVAL: f64 = 0.1;
+
+    fn new(path: PathBuf) -> Self {
+        AsciicastSink { path, width: 0, height: 0, frames: vec![] }
+    }
+}
+
+impl FrameSink for AsciicastSink {
+    fn push(&mut self, frame: &Frame) -> io::Result<()> {
+        self.width = self.width.max(frame.width());
+        self.height = self.height.max(frame.height());
+        let rows = (0..frame.height()).map(|y| frame.row(y).iter().collect::<String>()).join("\r\n");
+        self.frames.push(format!("\x1b[H\x1b[2J{}", rows));
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(&self.path)?);
+        writeln!(file, r#"{{"version": 2, "width": {}, "height": {}}}"#, self.width, self.height)?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let event = serde_json::json!([i as f64 * Self::INTERVAL, "o", frame]);
+            writeln!(file, "{}", event)?;
+        }
+        file.flush()
+    }
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Recording>> = Mutex::new(None);
+
+struct Recording {
+    sink: Box<dyn FrameSink + Send>,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+/// Sends the frames of the solvers to `sink` until [`finish`].
+pub fn start(sink: Box<dyn FrameSink + Send>) {
+    *SINK.lock().unwrap() = Some(Recording { sink, frames: 0, error: None });
+    ACTIVE.store(true, Ordering::Relaxed);
+}
+
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Passes the frame built by `frame` to the sink, if there is one.
+pub fn frame(frame: impl FnOnce() -> Frame) {
+    if !is_active() {
+        return;
+    }
+    let frame = frame();
+    if let Some(recording) = SINK.lock().unwrap().as_mut() {
+        if recording.error.is_none() {
+            recording.error = recording.sink.push(&frame).err();
+            recording.frames += 1;
+        }
+    }
+}
+
+/// Finishes the sink of [`start`], returning how many frames it got or the first error writing them.
+pub fn finish() -> io::Result<usize> {
+    ACTIVE.store(false, Ordering::Relaxed);
+    match SINK.lock().unwrap().take() {
+        Some(Recording { error: Some(e), .. }) => Err(e),
+        Some(Recording { mut sink, frames, .. }) => sink.finish().map(|_| frames),
+        None => Ok(0),
+    }
+}
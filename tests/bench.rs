@@ -0,0 +1,77 @@
+//! Checks the benchmark reports and their comparison against a baseline.
+
+use std::time::Duration;
+use advent_of_code_2022::bench::{self, BenchReport, Phase, Timing};
+use advent_of_code_2022::{days, LoadError};
+use advent_of_code_2022::memory::Usage;
+use advent_of_code_2022::params::Params;
+
+fn timing(day: u8, phase: Phase, median_ms: u64) -> Timing {
+    let median = Duration::from_millis(median_ms);
+    Timing { day, phase, min: median, median, max: median, memory: None }
+}
+
+#[test]
+fn times_every_phase() {
+    let timings = bench::bench(days::get(1).unwrap(), "1\n2\n\n3\n", &Params::default(), 3).unwrap();
+    let phases = timings.iter().map(|t| t.phase).collect::<Vec<_>>();
+    assert_eq!(phases, vec![Phase::Parse, Phase::PartOne, Phase::PartTwo]);
+    assert!(timings.iter().all(|t| t.min <= t.median && t.median <= t.max));
+}
+
+#[test]
+#[should_panic(expected = "at least one run")]
+fn zero_runs_are_refused() {
+    let _ = bench::bench(days::get(1).unwrap(), "1\n", &Params::default(), 0);
+}
+
+#[test]
+fn solves_the_puzzle_of_the_parameters() {
+    let day = days::get(15).unwrap();
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n";
+    let mut params = Params::default();
+    params.set("range", "-1");
+    assert!(matches!(bench::bench(day, input, &params, 1), Err(LoadError::Params(_))));
+    params.set("rows", "10");
+    assert!(matches!(bench::bench(day, input, &params, 1), Err(LoadError::Params(_))));
+}
+
+#[test]
+fn flags_slowdowns_over_the_threshold() {
+    let baseline = BenchReport { runs: 5, timings: vec![timing(15, Phase::PartOne, 100), timing(15, Phase::PartTwo, 100)] };
+    let current = BenchReport { runs: 5, timings: vec![timing(15, Phase::PartOne, 105), timing(15, Phase::PartTwo, 150)] };
+
+    let changes = current.compare(&baseline, 10.0);
+    assert!(!changes[&(15, Phase::PartOne)].is_regression);
+    assert!(changes[&(15, Phase::PartTwo)].is_regression);
+    assert_eq!(changes[&(15, Phase::PartTwo)].percent.round(), 50.0);
+}
+
+#[test]
+fn ignores_noise_on_very_fast_phases() {
+    let fast = |ns| Timing { day: 1, phase: Phase::Parse, min: Duration::from_nanos(ns), median: Duration::from_nanos(ns), max: Duration::from_nanos(ns), memory: None };
+    let baseline = BenchReport { runs: 5, timings: vec![fast(200)] };
+    let current = BenchReport { runs: 5, timings: vec![fast(400)] };
+    assert!(!current.compare(&baseline, 10.0)[&(1, Phase::Parse)].is_regression);
+}
+
+#[test]
+fn flags_a_growing_peak_heap_usage() {
+    let with_peak = |day, peak| Timing { memory: Some(Usage { peak, allocations: 1 }), ..timing(day, Phase::PartOne, 100) };
+    let baseline = BenchReport { runs: 5, timings: vec![with_peak(15, 100 << 20), with_peak(16, 1 << 10)] };
+    let current = BenchReport { runs: 5, timings: vec![with_peak(15, 200 << 20), with_peak(16, 4 << 10)] };
+
+    let changes = current.compare(&baseline, 10.0);
+    assert!(changes[&(15, Phase::PartOne)].is_regression);
+    assert_eq!(changes[&(15, Phase::PartOne)].peak_percent, Some(100.0));
+    // a few kilobytes more are not worth failing over
+    assert!(!changes[&(16, Phase::PartOne)].is_regression);
+}
+
+#[test]
+fn reports_survive_a_json_round_trip() {
+    let report = BenchReport { runs: 7, timings: vec![timing(3, Phase::Parse, 2)] };
+    let json = report.to_json();
+    assert!(json.contains("\"median_ns\": 2000000"));
+    assert_eq!(serde_json::from_str::<BenchReport>(&json).unwrap(), report);
+}
@@ -0,0 +1,31 @@
+//! Checks that solvers stop when their token is cancelled or runs out of time.
+
+use std::time::Duration;
+use advent_of_code_2022::cancel::{Cancelled, Token};
+use advent_of_code_2022::days::day_15::Day15;
+use advent_of_code_2022::{input, Part, Solution};
+
+#[test]
+fn clones_share_the_cancellation_and_budgets_run_out() {
+    let token = Token::none();
+    let clone = token.clone();
+    assert_eq!(clone.check(), Ok(()));
+    token.cancel();
+    assert_eq!(clone.check(), Err(Cancelled));
+
+    assert!(!Token::with_budget(Duration::from_secs(3600)).is_cancelled());
+    assert!(Token::with_budget(Duration::ZERO).is_cancelled());
+    assert!(!Token::with_budget(Duration::MAX).is_cancelled());
+}
+
+#[test]
+fn a_scan_gives_up_on_a_cancelled_token() {
+    let input = input::resolve(15, Some(&input::repo_dir().join("input/day_15_sample.txt"))).unwrap();
+    let mut solution = Day15::parse(&input.text).unwrap();
+    solution.configure(&input.params).unwrap();
+    assert_eq!(solution.solve_until(Part::Two, &Token::none()), Ok(56000011.into()));
+
+    let token = Token::none();
+    token.cancel();
+    assert_eq!(solution.solve_until(Part::Two, &token), Err(Cancelled));
+}
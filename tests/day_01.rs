@@ -0,0 +1,96 @@
+//! Checks the streaming reader and the inventory model of day 1 beyond what the puzzle asks.
+
+use std::io::{BufReader, Read};
+use advent_of_code_2022::days::day_01::{self, Day01, Elf, Inventory};
+use advent_of_code_2022::parse::ParseError;
+use advent_of_code_2022::Solution;
+
+/// The elves of the example in the puzzle text.
+const SAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn crlf_trailing_newlines_and_stray_blank_lines_are_no_elves() {
+    let inventory = "\r\n\r\n1000\r\n2000\r\n\r\n \r\n\r\n4000\r\n\r\n5000\n6000\n\n\n\n";
+    let totals = day_01::totals(inventory.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(totals, vec![3000, 4000, 11000]);
+}
+
+#[test]
+fn top_k_keeps_the_largest_in_order() {
+    assert_eq!(day_01::top_k(SAMPLE.as_bytes(), 3).unwrap(), vec![24000, 11000, 10000]);
+    assert_eq!(day_01::top_k(SAMPLE.as_bytes(), 10).unwrap(), vec![24000, 11000, 10000, 6000, 4000]);
+    assert_eq!(day_01::top_k(SAMPLE.as_bytes(), 0).unwrap(), Vec::<usize>::new());
+}
+
+#[test]
+fn a_long_inventory_is_read_in_small_pieces() {
+    /// Ten thousand elves with 1..=10000 calories, produced a few bytes at a time.
+    struct Elves(String, usize);
+
+    impl Read for Elves {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(7).min(self.0.len() - self.1);
+            buf[..n].copy_from_slice(&self.0.as_bytes()[self.1..self.1 + n]);
+            self.1 += n;
+            Ok(n)
+        }
+    }
+
+    let text = (1..=10000).map(|calories| format!("{}\n\n", calories)).collect::<String>();
+    let top = day_01::top_k(BufReader::with_capacity(16, Elves(text, 0)), 3).unwrap();
+    assert_eq!(top, vec![10000, 9999, 9998]);
+}
+
+#[test]
+fn bad_lines_are_reported_where_they_are() {
+    let e = day_01::top_k("100\r\n\r\n200\r\n2x0\r\n".as_bytes(), 3).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (4, 1, "2x0"));
+    assert_eq!(Day01::parse("\n\n").err().map(|e: ParseError| e.expected), Some("the calories of an elf".to_string()));
+}
+
+#[test]
+fn the_inventory_answers_questions_about_items_and_loads() {
+    let inventory = Inventory::read(SAMPLE.as_bytes()).unwrap();
+    assert_eq!(inventory.elves[2], Elf { items: vec![5000, 6000] });
+    assert_eq!(inventory.heaviest_item(), Some((4, 10000)));
+    assert_eq!(inventory.median_load(), Some(10000));
+    assert_eq!(inventory.percentile(0.0), Some(4000));
+    assert_eq!(inventory.percentile(80.0), Some(11000));
+    assert_eq!(inventory.percentile(100.0), Some(24000));
+    assert_eq!(inventory.above(10000), vec![2, 3]);
+    assert_eq!(Inventory::default().median_load(), None);
+}
+
+#[test]
+fn percentiles_of_a_hundred_elves_are_exact_ranks() {
+    // the elves carry 1 to 100 calories, so the load at rank r is r
+    let inventory = Inventory { elves: (1..=100).map(|calories| Elf { items: vec![calories] }).collect() };
+    for percent in 1..=100 {
+        assert_eq!(inventory.percentile(percent as f64), Some(percent), "p{}", percent);
+    }
+    assert_eq!(inventory.percentile(7.0), Some(7));
+    assert_eq!(inventory.percentile(6.5), Some(7));
+    assert_eq!(inventory.percentile(-1.0), None);
+    assert_eq!(inventory.percentile(100.5), None);
+    assert_eq!(inventory.percentile(f64::NAN), None);
+}
+
+#[test]
+fn rebalancing_keeps_every_item_and_gets_close_to_the_bound() {
+    let inventory = Inventory::read(SAMPLE.as_bytes()).unwrap();
+    let items = |elves: &[Elf]| {
+        let mut items = elves.iter().flat_map(|elf| elf.items.clone()).collect::<Vec<_>>();
+        items.sort();
+        items
+    };
+
+    let plan = inventory.rebalance(3);
+    assert_eq!(items(&plan.elves), items(&inventory.elves));
+    assert_eq!(plan.lower_bound, 55000_usize.div_ceil(3));
+    assert_eq!(plan.max_load(), 19000);
+    assert!(plan.gap() < 0.04, "{}", plan.gap());
+
+    // a single item heavier than the average bounds the plan
+    let lopsided = Inventory::read("100\n\n1\n\n2\n".as_bytes()).unwrap().rebalance(2);
+    assert_eq!((lopsided.lower_bound, lopsided.max_load(), lopsided.gap()), (100, 100, 0.0));
+}
@@ -0,0 +1,281 @@
+//! Checks the rule tables of day 2 against games whose rules are written out by hand, and the
+//! analysis of a guide against totals and matches worked out on paper.
+
+use advent_of_code_2022::cancel::{Cancelled, Token};
+use advent_of_code_2022::days::day_02::{self, Day02, Encoding, Game, Letters, Record, SecondColumn, Shape, Strategy};
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use advent_of_code_2022::generate;
+use advent_of_code_2022::params::Params;
+use advent_of_code_2022::{Answer, LoadError, Solution};
+
+fn solve(guide: &str, params: &str) -> Result<(Answer, Answer), LoadError> {
+    let solution = Day02::load(guide, &Params::parse(params).unwrap())?;
+    Ok((solution.part_one(), solution.part_two()))
+}
+
+#[test]
+fn the_example_of_the_puzzle_text() {
+    assert_eq!(solve("A Y\nB X\nC Z\n", "").unwrap(), (15.into(), 12.into()));
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock_follows_its_ten_rules() {
+    const SHAPES: [&str; 5] = ["rock", "spock", "paper", "lizard", "scissors"];
+    const RULES: [(&str, &str); 10] = [
+        ("scissors", "paper"), ("paper", "rock"), ("rock", "lizard"), ("lizard", "spock"), ("spock", "scissors"),
+        ("scissors", "lizard"), ("lizard", "paper"), ("paper", "spock"), ("spock", "rock"), ("rock", "scissors"),
+    ];
+    let params = "shapes = rock, spock, paper, lizard, scissors\nshape_scores = 1, 2, 3, 4, 5\n\
+                  opponent_symbols = A, B, C, D, E\nown_symbols = V, W, X, Y, Z\n";
+
+    for (opponent, their_shape) in SHAPES.iter().enumerate() {
+        for (own, our_shape) in SHAPES.iter().enumerate() {
+            let guide = format!("{} {}\n", "ABCDE".as_bytes()[opponent] as char, "VWXYZ".as_bytes()[own] as char);
+            let outcome = if opponent == own {
+                3
+            } else if RULES.contains(&(our_shape, their_shape)) {
+                6
+            } else {
+                assert!(RULES.contains(&(their_shape, our_shape)));
+                0
+            };
+            let (part_one, _) = solve(&guide, params).unwrap();
+            assert_eq!(part_one, (outcome + own + 1).into(), "{} against {}", our_shape, their_shape);
+        }
+    }
+}
+
+#[test]
+fn symbols_before_the_middle_one_lose_and_after_it_win_in_part_two() {
+    // lizard and scissors lose to rock, spock and paper beat it, the first of them after rock is played
+    let params = "shapes = rock, spock, paper, lizard, scissors\nopponent_symbols = A, B, C, D, E\n\
+                  own_symbols = V, W, X, Y, Z\nshape_scores = 1, 2, 3, 4, 5\n";
+    assert_eq!(solve("A V\nA W\nA X\nA Y\nA Z\n", params).unwrap().1, (4 + 4 + 4 + 8 + 8).into());
+}
+
+#[test]
+fn games_that_do_not_add_up_are_rejected() {
+    let Err(LoadError::Params(e)) = solve("A X\n", "shapes = rock, paper\n") else { panic!() };
+    assert_eq!((e.line, e.column, e.expected.as_str()), (1, 10, "an odd number of shapes, at least three for `shapes`"));
+
+    let Err(LoadError::Params(e)) = solve("A X\n", "shape_scores = 1, 2\n") else { panic!() };
+    assert_eq!(e.expected, "a score for each of the 3 shapes for `shape_scores`");
+
+    let Err(LoadError::Params(e)) = solve("A X\n", "own_symbols = X, X, Z\n") else { panic!() };
+    assert_eq!(e.expected, "a different symbol for each of the 3 shapes for `own_symbols`");
+
+    let Err(LoadError::Input(e)) = solve("A X\nB W\n", "own_symbols = X, Y, Z\n") else { panic!() };
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "W"));
+}
+
+#[test]
+fn a_guide_is_read_with_the_symbols_of_the_puzzle_unless_told_otherwise() {
+    let rpsls = "A V\nE Z\n";
+    assert_eq!(Day02::parse(rpsls).err().map(|e| (e.line, e.found)), Some((1, "V".to_string())));
+    let params = "shapes = rock, spock, paper, lizard, scissors\nshape_scores = 1, 2, 3, 4, 5\n\
+                  opponent_symbols = A, B, C, D, E\nown_symbols = V, W, X, Y, Z\n";
+    assert_eq!(solve(rpsls, params).unwrap().0, (4 + 8).into());
+
+    // a solution that is never configured plays the puzzle's game
+    let solution = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+    assert_eq!((solution.part_one(), solution.part_two()), (15.into(), 12.into()));
+    let mut solution = solution;
+    assert!(solution.configure(&Params::parse("own_symbols = P, Q, R\n").unwrap()).is_err());
+    assert_eq!(solution.part_one(), 15.into());
+}
+
+fn parsed(guide: &str) -> Day02 {
+    Day02::parse(guide).unwrap()
+}
+
+#[test]
+fn the_best_worst_and_random_totals_of_the_example() {
+    let solution = parsed("A Y\nB X\nC Z\n");
+    // paper, scissors and rock win for 8 + 9 + 7, scissors, rock and paper lose for 3 + 1 + 2
+    assert_eq!((solution.best_total(), solution.worst_total()), (24, 6));
+
+    let random = solution.random_totals();
+    assert!((random.probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((random.probability(24) - 1.0 / 27.0).abs() < 1e-9);
+    assert!((random.probability(6) - 1.0 / 27.0).abs() < 1e-9);
+    assert_eq!(random.probability(5), 0.0);
+    // every shape scores 5 on average against every other
+    assert!((random.mean() - 15.0).abs() < 1e-9);
+    assert!((random.at_least(6) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn the_strategies_play_a_cycling_opponent() {
+    let solution = parsed(&"A X\nB X\nC X\n".repeat(3));
+    let tournament = solution.tournament(day_02::strategies(solution.game()));
+    let against_opponent = tournament.against_opponent();
+    let record = |name: &str| against_opponent.iter().find(|(n, _)| *n == name).unwrap().1;
+
+    assert_eq!(tournament.names, ["opponent", "always rock", "always paper", "always scissors", "cycle", "frequency", "markov"]);
+    assert_eq!(record("always rock"), Record { won: 3, draws: 3, lost: 3, score: 3 * 7 + 3 * 4 + 3 });
+    assert_eq!(record("cycle"), Record { won: 0, draws: 9, lost: 0, score: 3 * (4 + 5 + 6) });
+    // it knows every transition after the first three rounds, and beats rock at first by chance
+    let markov = record("markov");
+    assert_eq!((markov.won, markov.draws, markov.lost), (7, 1, 1));
+    assert!((markov.win_rate() - 7.0 / 9.0).abs() < 1e-9);
+    let opponent = tournament.records[0][6];
+    assert_eq!((opponent.won, opponent.draws, opponent.lost), (1, 1, 7));
+    assert_eq!(tournament.overall(4).rounds(), 6 * 9);
+}
+
+/// Plays whatever the other player played last.
+struct Copycat;
+
+impl Strategy for Copycat {
+    fn name(&self, _: &Game) -> String {
+        "copycat".to_string()
+    }
+
+    fn play(&mut self, theirs: &[Shape], game: &Game) -> Shape {
+        theirs.last().copied().unwrap_or_else(|| game.shape("rock").unwrap())
+    }
+}
+
+#[test]
+fn strategies_can_be_brought_along() {
+    let solution = parsed("A X\nA X\nB X\nB X\n");
+    let tournament = solution.tournament(vec![Box::new(Copycat)]);
+    assert_eq!(tournament.names, ["opponent", "copycat"]);
+    // rock against rock, rock against rock, rock against paper, paper against paper
+    assert_eq!(tournament.records[1][0], Record { won: 0, draws: 3, lost: 1, score: 4 + 4 + 1 + 5 });
+    assert_eq!(tournament.records[1][0].loss_rate(), 0.25);
+}
+
+fn encoding(opponent: [&str; 3], own: [&str; 3]) -> Encoding {
+    Encoding { opponent: opponent.map(String::from).to_vec(), own: own.map(String::from).to_vec() }
+}
+
+fn encodings_for(guide: &str, game: &Game, column: SecondColumn, total: usize) -> Vec<Encoding> {
+    game.encodings_for(&Letters::read(guide).unwrap(), column, total, &Token::none()).unwrap()
+}
+
+#[test]
+fn every_encoding_found_adds_up_to_the_total() {
+    let guide = "A Y\nB X\nC Z\nA X\n";
+    for (column, total) in [(SecondColumn::Shapes, 19), (SecondColumn::Outcomes, 15)] {
+        let encodings = encodings_for(guide, &Game::default(), column, total);
+        assert!(encodings.contains(&encoding(["A", "B", "C"], ["X", "Y", "Z"])));
+        assert!(encodings.len() < 36);
+        for encoding in encodings {
+            let (part_one, part_two) = solve(guide, &encoding.to_string()).unwrap();
+            assert_eq!(if column == SecondColumn::Shapes { part_one } else { part_two }, total.into(), "{}", encoding);
+        }
+    }
+    assert_eq!(encodings_for(guide, &Game::default(), SecondColumn::Shapes, 1000), []);
+}
+
+#[test]
+fn a_guide_in_other_letters_is_decoded_from_its_total() {
+    // R is rock, P paper and Q scissors; M is rock, K paper and L scissors
+    let guide = "R K\nP L\nQ M\nR M\nP K\nQ L\nQ K\n";
+    let written = encoding(["R", "P", "Q"], ["M", "K", "L"]);
+    let total = Day02::load(guide, &Params::parse(&written.to_string()).unwrap()).unwrap().total(SecondColumn::Shapes);
+    assert!(Day02::parse(guide).is_err());
+
+    let encodings = encodings_for(guide, &Game::default(), SecondColumn::Shapes, total);
+    assert!(encodings.contains(&written), "{:?}", encodings);
+
+    // a column with fewer letters than shapes borrows the game's symbols for the rest: only
+    // scissors against rock and paper, in either order, loses and wins for 3 + 9
+    let encodings = encodings_for("R K\nP K\n", &Game::default(), SecondColumn::Shapes, 3 + 9);
+    assert_eq!(encodings.len(), 4);
+    assert!(encodings.contains(&encoding(["R", "P", "A"], ["X", "Y", "K"])), "{:?}", encodings);
+    assert_eq!(encodings_for("A K\nB L\nC M\nD N\n", &Game::default(), SecondColumn::Shapes, 0), []);
+}
+
+#[test]
+fn the_search_gives_up_on_a_cancelled_token() {
+    let params = Params::parse("shapes = rock, spock, paper, lizard, scissors\nshape_scores = 1, 2, 3, 4, 5\n\
+                                opponent_symbols = A, B, C, D, E\nown_symbols = V, W, X, Y, Z\n").unwrap();
+    let game = Game::configured(&params).unwrap();
+    let letters = Letters::read("A W\nB X\nC Z\nD V\nE Y\nA Z\n").unwrap();
+    // the recorded part one answer of the example, among the 120 * 120 encodings
+    let encodings = game.encodings_for(&letters, SecondColumn::Shapes, 44, &Token::none()).unwrap();
+    assert!(encodings.contains(game.encoding()));
+
+    let token = Token::none();
+    token.cancel();
+    assert_eq!(game.encodings_for(&letters, SecondColumn::Shapes, 44, &token), Err(Cancelled));
+}
+
+/// A guide of `rounds` rounds from the generator of day 2, the same for the same seed.
+fn generated(rounds: usize, seed: u64) -> String {
+    generate::ALL.iter().find(|generator| generator.day == 2).unwrap().generate(rounds, seed)
+}
+
+#[test]
+fn the_random_totals_of_a_generated_guide_count_every_choice_of_shapes() {
+    let guide = generated(6, 7);
+    let solution = parsed(&guide);
+    let opponent = guide.lines().map(|line| &line[..1]).collect::<Vec<_>>();
+
+    // all 3^6 ways of playing, each scored as a guide of its own
+    let mut totals = HashMap::<usize, usize>::new();
+    for choice in 0..729 {
+        let own = (0..6).map(|round| ["X", "Y", "Z"][choice / 3usize.pow(round) % 3]);
+        let played = opponent.iter().zip(own).map(|(opponent, own)| format!("{} {}\n", opponent, own)).collect::<String>();
+        let total = Day02::parse(&played).unwrap().total(SecondColumn::Shapes);
+        *totals.entry(total).or_default() += 1;
+    }
+
+    let random = solution.random_totals();
+    for (total, probability) in random.probabilities.iter().enumerate() {
+        let ways = totals.get(&total).copied().unwrap_or_default();
+        assert!((probability - ways as f64 / 729.0).abs() < 1e-12, "total {}", total);
+    }
+    assert_eq!(solution.best_total(), *totals.keys().max().unwrap());
+    assert_eq!(solution.worst_total(), *totals.keys().min().unwrap());
+}
+
+#[test]
+fn the_tournament_of_a_generated_guide() {
+    let guide = generated(200, 1);
+    let solution = parsed(&guide);
+    let tournament = solution.tournament(day_02::strategies(solution.game()));
+    let against_opponent = tournament.against_opponent();
+
+    // a fixed shape scores what the guide scores with that symbol in every round
+    for (name, own) in [("always rock", "X"), ("always paper", "Y"), ("always scissors", "Z")] {
+        let fixed = guide.lines().map(|line| format!("{} {}\n", &line[..1], own)).collect::<String>();
+        let (_, record) = against_opponent.iter().find(|(n, _)| *n == name).unwrap();
+        assert_eq!(record.score, Day02::parse(&fixed).unwrap().total(SecondColumn::Shapes), "{}", name);
+        assert_eq!(record.rounds(), 200);
+    }
+    // the rest depends on the shapes the generator drew, so only what holds for any guide: every
+    // match is a mirror of its other side, and every round scores between 1 and 6 + 3
+    for (i, records) in tournament.records.iter().enumerate() {
+        for (j, record) in records.iter().enumerate().filter(|(j, _)| *j != i) {
+            let other = tournament.records[j][i];
+            assert_eq!((record.won, record.draws, record.lost), (other.lost, other.draws, other.won), "{} against {}", i, j);
+            assert_eq!(record.rounds(), 200);
+            assert!((200..=200 * 9).contains(&record.score), "{} against {}", i, j);
+        }
+    }
+    for (name, record) in against_opponent {
+        assert!((solution.worst_total()..=solution.best_total()).contains(&record.score), "{}", name);
+    }
+}
+
+#[test]
+fn aoc_guide_prints_the_analysis_of_a_guide() {
+    let mut aoc = Command::new(env!("CARGO_BIN_EXE_aoc")).args(["guide", "--input", "-"])
+        .stdin(Stdio::piped()).stdout(Stdio::piped()).spawn().unwrap();
+    aoc.stdin.take().unwrap().write_all(b"A Y\nB X\nC Z\n").unwrap();
+    let output = aoc.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("3 rounds, scoring 15 in part one and 12 in part two"), "{}", stdout);
+    assert!(stdout.contains("between 6 and 24"), "{}", stdout);
+    assert!(stdout.contains("15.0 on average"), "{}", stdout);
+    let cycle = stdout.lines().find(|line| line.starts_with("cycle")).unwrap();
+    // rock, paper and scissors against rock, paper and scissors
+    assert_eq!(cycle.split_whitespace().collect::<Vec<_>>(), ["cycle", "0.0%", "100.0%", "0.0%", "15"]);
+}
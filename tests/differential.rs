@@ -0,0 +1,85 @@
+//! Checks the days with clever shortcuts against the slow solutions in `reference` on small
+//! random inputs, see `differential::check`.
+
+mod reference;
+
+use advent_of_code_2022::differential::{self, Case};
+use advent_of_code_2022::params::Params;
+use advent_of_code_2022::{days, Answer, Part};
+use itertools::Itertools;
+
+fn solve(day: u8, case: &impl Case, params: &Params) -> Vec<Answer> {
+    let solution = days::get(day).unwrap().load(&case.input(), params).unwrap();
+    Part::all().into_iter().map(|part| solution.solve(part)).collect()
+}
+
+#[test]
+fn day_11() {
+    differential::check(20, reference::day_11::generate, reference::day_11::reference, |case| solve(11, case, &Params::default()))
+        .unwrap_or_else(|mismatch| panic!("{}", mismatch));
+}
+
+#[test]
+fn day_15() {
+    differential::check(100, reference::day_15::generate, reference::day_15::reference, |case| {
+        let mut params = Params::default();
+        params.set("row", case.row.to_string());
+        params.set("range", case.range.to_string());
+        solve(15, case, &params)
+    }).unwrap_or_else(|mismatch| panic!("{}\nwith row {} and range {}", mismatch, mismatch.case.row, mismatch.case.range));
+}
+
+#[test]
+fn day_16() {
+    differential::check(30, reference::day_16::generate, reference::day_16::reference, |case| solve(16, case, &Params::default()))
+        .unwrap_or_else(|mismatch| panic!("{}", mismatch));
+}
+
+#[test]
+fn day_19() {
+    differential::check(6, reference::day_19::generate, reference::day_19::reference, |case| solve(19, case, &Params::default()))
+        .unwrap_or_else(|mismatch| panic!("{}", mismatch));
+}
+
+#[test]
+fn day_22() {
+    differential::check(100, reference::day_22::generate, reference::day_22::reference, |case| solve(22, case, &Params::default()))
+        .unwrap_or_else(|mismatch| panic!("{}", mismatch));
+}
+
+/// Elves with one snack each, for a day 1 that forgets the heavy snacks.
+#[derive(Clone, Debug)]
+struct Snacks(Vec<usize>);
+
+impl Case for Snacks {
+    fn input(&self) -> String {
+        self.0.iter().join("\n\n") + "\n"
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let fewer = (0..self.0.len()).filter(|_| self.0.len() > 1).map(|i| {
+            let mut snacks = self.0.clone();
+            snacks.remove(i);
+            Snacks(snacks)
+        });
+        let lighter = (0..self.0.len()).filter(|i| self.0[*i] > 0).map(|i| {
+            let mut snacks = self.0.clone();
+            snacks[i] -= 1;
+            Snacks(snacks)
+        });
+        fewer.chain(lighter).collect()
+    }
+}
+
+#[test]
+fn a_mismatch_is_shrunk_to_a_minimal_input() {
+    let mismatch = differential::check(
+        50,
+        |rng| Snacks((0..rng.between(1, 5)).map(|_| rng.between(0, 2000)).collect()),
+        |snacks| Some(vec![(*snacks.0.iter().max().unwrap()).into()]),
+        |snacks| vec![(*snacks.0.iter().filter(|calories| **calories < 1000).max().unwrap()).into()],
+    ).unwrap_err();
+    assert_eq!(mismatch.case.0, vec![1000]);
+    assert!(mismatch.found.is_err(), "{}", mismatch);
+}
+
@@ -0,0 +1,50 @@
+//! Checks that the generated inputs are reproducible and that the solutions accept them.
+
+use advent_of_code_2022::generate::{self, Generator};
+use advent_of_code_2022::{days, Answer, Part};
+
+fn solve(generator: &Generator, size: usize, seed: u64) -> Vec<Answer> {
+    let input = generator.generate(size, seed);
+    let solution = days::get(generator.day).unwrap().parse(&input)
+        .unwrap_or_else(|e| panic!("day {} can not parse its generated input: {}\n{}", generator.day, e, input));
+    Part::all().into_iter().map(|part| solution.solve(part)).collect()
+}
+
+#[test]
+fn the_seed_decides_the_input() {
+    for generator in generate::ALL {
+        let input = generator.generate(8, 42);
+        assert_eq!(input, generator.generate(8, 42), "day {}", generator.day);
+        assert_ne!(input, generator.generate(8, 43), "day {}", generator.day);
+    }
+}
+
+#[test]
+fn every_generated_input_parses() {
+    for generator in generate::ALL {
+        for (size, seed) in [(0, 1), (1, 2), (20, 3)] {
+            let input = generator.generate(size, seed);
+            assert!(days::get(generator.day).unwrap().parse(&input).is_ok(), "day {} size {}:\n{}", generator.day, size, input);
+        }
+    }
+}
+
+#[test]
+fn small_generated_inputs_are_solved() {
+    // day 15 always scans the rows and columns of the full puzzle, which takes too long here
+    for generator in generate::ALL.iter().filter(|g| g.day != 15) {
+        let size = match generator.day {
+            16 | 19 => 2,
+            _ => 10,
+        };
+        for seed in 0..3 {
+            solve(generator, size, seed);
+        }
+    }
+}
+
+#[test]
+fn the_marker_of_day_6_is_at_the_end() {
+    let day_06 = generate::get(6).unwrap();
+    assert_eq!(solve(day_06, 5000, 7)[1], Answer::from(5000usize));
+}
@@ -0,0 +1,49 @@
+//! Checks the shared grid types the map based days are built on.
+
+use advent_of_code_2022::grid::{Direction, Grid, Point, SparseGrid, Turn};
+use advent_of_code_2022::parse::ParseError;
+
+#[test]
+fn points_are_ordered_row_by_row() {
+    let mut points = vec![Point::new(2, 0), Point::new(0, 1), Point::new(1, 0)];
+    points.sort();
+    assert_eq!(points, vec![Point::new(1, 0), Point::new(2, 0), Point::new(0, 1)]);
+}
+
+#[test]
+fn directions_turn_and_step() {
+    assert_eq!(Direction::Up.turn(Turn::Right), Direction::Right);
+    assert_eq!(Direction::Left.turn(Turn::Left), Direction::Down);
+    assert_eq!(Direction::Down.opposite(), Direction::Up);
+    assert_eq!(Point::ORIGIN.step(Direction::Up), Point::new(0, -1));
+    assert_eq!(Point::new(3, 3).neighbours_with_diagonals().len(), 8);
+    assert_eq!(Point::new(1, 1).neighbours().map(|p| p.manhattan(Point::new(1, 1))), [1; 4]);
+}
+
+#[test]
+fn dense_grid_is_parsed_from_a_character_map() {
+    let grid = Grid::parse("123\n456\n", |c| c.to_digit(10).ok_or_else(|| ParseError::new("a digit", c))).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.get(Point::new(-1, 0)), None);
+    assert_eq!(grid.column(1).to_vec(), vec![2, 5]);
+}
+
+#[test]
+fn dense_grid_rejects_ragged_rows_and_bad_cells() {
+    let digit = |c: char| c.to_digit(10).ok_or_else(|| ParseError::new("a digit", c));
+
+    let e = Grid::parse("123\n45\n", digit).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 3));
+
+    let e = Grid::parse("123\n4x6\n", digit).unwrap_err();
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "x"));
+}
+
+#[test]
+fn sparse_grid_keeps_only_some_cells() {
+    let grid = SparseGrid::parse("..#\n#..\n", |c| Ok((c == '#').then_some(()))).unwrap();
+    assert_eq!(grid.len(), 2);
+    assert!(grid.contains(Point::new(2, 0)));
+    assert_eq!(grid.bounds(), Some((Point::new(0, 0), Point::new(2, 1))));
+}
@@ -0,0 +1,137 @@
+//! Checks how the input of a day is found and which files are rejected before a solver sees them.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use advent_of_code_2022::input::{self, Problem};
+use advent_of_code_2022::params::Param;
+
+/// Held by the tests that point [`input::DIR_VAR`] somewhere else.
+static ENV: Mutex<()> = Mutex::new(());
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn the_input_directory_is_searched_before_the_repository() {
+    let dir = scratch_dir("dir");
+    let _env = ENV.lock().unwrap();
+    std::env::set_var(input::DIR_VAR, &dir);
+    let candidates = input::candidates(7, None);
+    std::env::remove_var(input::DIR_VAR);
+
+    let repo = input::repo_dir().join("input");
+    assert_eq!(candidates, vec![
+        dir.join("day_07.txt"),
+        dir.join("day_07").join("input.txt"),
+        repo.join("day_07.txt"),
+        repo.join("day_07").join("input.txt"),
+    ]);
+}
+
+#[test]
+fn an_explicit_path_is_the_only_candidate() {
+    let dir = scratch_dir("explicit");
+    let path = dir.join("custom.txt");
+    fs::write(&path, "1000\n2000\n").unwrap();
+
+    let input = input::resolve(1, Some(&path)).unwrap();
+    assert_eq!(input.text, "1000\n2000\n");
+    assert_eq!(input.key(), path);
+
+    let e = input::resolve(1, Some(&dir.join("missing.txt"))).unwrap_err();
+    assert_eq!(e.tried, vec![(dir.join("missing.txt"), Problem::Missing)]);
+}
+
+#[test]
+fn inputs_of_the_repository_are_keyed_relative_to_it() {
+    let input = input::resolve(1, Some(&input::repo_dir().join("input/day_01.txt"))).unwrap();
+    assert_eq!(input.key(), PathBuf::from("input/day_01.txt"));
+}
+
+#[test]
+fn empty_and_misplaced_content_is_rejected() {
+    assert_eq!(input::check(""), Err(Problem::Empty));
+    assert_eq!(input::check(" \n\r\n"), Err(Problem::Empty));
+    assert!(matches!(input::check("day_11.txt\n"), Err(Problem::Malformed(_))));
+    assert!(matches!(input::check("<!DOCTYPE html>\n<html>"), Err(Problem::Malformed(_))));
+    assert!(matches!(input::check("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"), Err(Problem::Malformed(_))));
+    assert_eq!(input::check("A Y\nB X\n"), Ok(()));
+}
+
+#[test]
+fn the_error_lists_every_candidate() {
+    let dir = scratch_dir("tried");
+    let empty = dir.join("empty.txt");
+    fs::write(&empty, "\n").unwrap();
+
+    let e = input::resolve(17, Some(&empty)).unwrap_err();
+    assert_eq!(e.to_string(), format!("{}: is empty", empty.display()));
+
+    let e = input::InputError { day: 17, tried: vec![(empty.clone(), Problem::Empty), (dir.join("day_17.txt"), Problem::Missing)] };
+    assert_eq!(e.to_string(), format!(
+        "no usable input for day 17, tried:\n  {}: is empty\n  {}: does not exist",
+        empty.display(), dir.join("day_17.txt").display()));
+}
+
+#[test]
+fn every_input_of_a_day_is_found_with_its_parameters() {
+    let dir = scratch_dir("all");
+    fs::create_dir_all(dir.join("day_15")).unwrap();
+    fs::write(dir.join("day_15.txt"), "mine\n").unwrap();
+    fs::write(dir.join("day_15").join("bob.txt"), "bob\n").unwrap();
+    fs::write(dir.join("day_15").join("alice.txt"), "alice\n").unwrap();
+    fs::write(dir.join("day_15").join("alice.params"), "# the example\nrow = 10\n\nrange=20\n").unwrap();
+    fs::write(dir.join("day_15").join("notes.md"), "not an input\n").unwrap();
+
+    let _env = ENV.lock().unwrap();
+    std::env::set_var(input::DIR_VAR, &dir);
+    let inputs = input::resolve_all(15).unwrap().inputs;
+    std::env::remove_var(input::DIR_VAR);
+
+    let paths = inputs.iter().map(|input| input.path.clone()).collect::<Vec<_>>();
+    assert_eq!(paths, vec![dir.join("day_15.txt"), dir.join("day_15/alice.txt"), dir.join("day_15/bob.txt")]);
+    let param = |name| Param { name, default: "0", about: "" };
+    assert_eq!(inputs[1].params.value::<isize>(&param("row")), Ok(10));
+    assert_eq!(inputs[1].params.value::<isize>(&param("range")), Ok(20));
+    assert!(inputs[0].params.is_empty() && inputs[2].params.is_empty());
+}
+
+#[test]
+fn the_inputs_of_a_day_that_cannot_be_used_are_returned_with_the_reason() {
+    let dir = scratch_dir("skipped");
+    fs::create_dir_all(dir.join("day_15")).unwrap();
+    fs::write(dir.join("day_15").join("alice.txt"), "alice\n").unwrap();
+    fs::write(dir.join("day_15").join("bob.txt"), "bob\n").unwrap();
+    fs::write(dir.join("day_15").join("bob.params"), "row 10\n").unwrap();
+    fs::write(dir.join("day_15").join("carol.txt"), "").unwrap();
+
+    let _env = ENV.lock().unwrap();
+    std::env::set_var(input::DIR_VAR, &dir);
+    let found = input::resolve_all(15).unwrap();
+    std::env::remove_var(input::DIR_VAR);
+
+    assert_eq!(found.inputs.iter().map(|input| input.path.clone()).collect::<Vec<_>>(), vec![dir.join("day_15/alice.txt")]);
+    let skipped = found.skipped.iter().map(|(path, problem)| (path.clone(), problem.to_string())).collect::<Vec<_>>();
+    assert_eq!(skipped, vec![
+        (dir.join("day_15/bob.txt"), format!(
+            "comes with invalid parameters: {}:1:1: expected `name = value`, found `row 10`", dir.join("day_15/bob.params").display())),
+        (dir.join("day_15/carol.txt"), "is empty".to_string()),
+    ]);
+}
+
+#[test]
+fn malformed_parameters_reject_their_input() {
+    let dir = scratch_dir("params");
+    let path = dir.join("day_15.txt");
+    fs::write(&path, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n").unwrap();
+    fs::write(dir.join("day_15.params"), "row = 10\nrange 20\n").unwrap();
+
+    let e = input::resolve(15, Some(&path)).unwrap_err();
+    assert_eq!(e.to_string(), format!(
+        "{}: comes with invalid parameters: {}:2:1: expected `name = value`, found `range 20`",
+        path.display(), dir.join("day_15.params").display()));
+}
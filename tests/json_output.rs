@@ -0,0 +1,82 @@
+//! Checks that `--format json` keeps stdout to one JSON record per line, with the trace on stderr.
+
+use std::process::Command;
+use serde_json::Value;
+
+fn aoc(args: &[&str]) -> (Vec<Value>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).output().unwrap();
+    let records = String::from_utf8(output.stdout).unwrap().lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{}: {}", e, line)))
+        .collect();
+    (records, String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn run_prints_a_record_per_part_and_the_trace_on_stderr() {
+    let (records, stderr) = aoc(&["run", "11", "--format", "json", "-v", "--param", "part_two_rounds=20"]);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["day"], 11);
+    assert_eq!(records[0]["part"], 1);
+    assert_eq!(records[0]["input"], "input/day_11.txt");
+    assert_eq!(records[0]["answer"], records[0]["expected"]);
+    assert_eq!(records[0]["status"], "ok");
+    assert!(records[1]["time_ns"].is_u64());
+    assert!(!stderr.is_empty());
+}
+
+#[test]
+fn verify_and_bench_print_a_record_per_input_and_part() {
+    let (records, _) = aoc(&["verify", "1", "--format", "json"]);
+    let statuses = records.iter().map(|record| record["status"].as_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(statuses, vec!["ok", "ok"]);
+
+    let (records, _) = aoc(&["bench", "1", "--runs", "1", "--format", "json"]);
+    let phases = records.iter().map(|record| record["phase"].as_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(phases, vec!["parse", "part_one", "part_two"]);
+    assert!(records.iter().all(|record| record["median_ns"].is_u64() && record["regression"] == false));
+}
+
+#[test]
+fn verify_and_bench_go_on_after_an_invalid_input() {
+    let dir = std::env::temp_dir().join(format!("aoc_json_verify_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (input, registry) = (dir.join("broken.txt"), dir.join("answers.txt"));
+    std::fs::write(&input, "A Q\n").unwrap();
+    std::fs::write(&registry, format!("02\t1\t{}\t15\n", input.display())).unwrap();
+
+    let (records, _) = aoc(&["verify", "2", "--format", "json", "--registry", registry.to_str().unwrap()]);
+    let invalid = records.iter().filter(|record| record["status"] == "invalid input").collect::<Vec<_>>();
+    assert_eq!(invalid.len(), 2);
+    assert!(invalid.iter().all(|record| record["error"].as_str().unwrap().contains("found `Q`")));
+    assert!(records.len() > 2 && records.iter().all(|record| record.get("error").is_some()));
+
+    let (records, _) = aoc(&["bench", "15", "--runs", "1", "--format", "json", "--param", "range=-1"]);
+    assert_eq!(records.len(), 3);
+    assert!(records.iter().all(|record| record["status"] == "invalid input" && record["error"].as_str().unwrap().contains("`range`")));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn an_invalid_input_is_a_record_with_the_error() {
+    let path = std::env::temp_dir().join(format!("aoc_json_{}.txt", std::process::id()));
+    std::fs::write(&path, "A Q\n").unwrap();
+    let (records, _) = aoc(&["run", "2", "--part", "1", "--format", "json", "--input", path.to_str().unwrap()]);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["status"], "invalid input");
+    assert!(records[0]["error"].as_str().unwrap().contains("found `Q`"));
+}
+
+#[test]
+fn a_panicking_part_is_a_record_with_the_message() {
+    let path = std::env::temp_dir().join(format!("aoc_json_panic_{}.txt", std::process::id()));
+    // the sensor sees every spot up to a range of 1, so part two has nothing to find
+    std::fs::write(&path, "Sensor at x=0, y=0: closest beacon is at x=5, y=0\n").unwrap();
+    let (records, stderr) = aoc(&["run", "15", "--format", "json", "--param", "range=1", "--input", path.to_str().unwrap()]);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!((&records[0]["status"], &records[0]["expected"]), (&"missing".into(), &Value::Null));
+    assert_eq!(records[1]["status"], "panicked");
+    assert_eq!(records[1]["error"], "every row up to 1 is covered");
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
@@ -0,0 +1,29 @@
+//! Checks that the counting allocator sees what a closure allocates.
+
+use advent_of_code_2022::memory::{self, Bytes, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn the_peak_and_the_allocations_of_a_closure_are_counted() {
+    let kept = vec![0u8; 1 << 16];
+    let (sum, usage) = memory::measure(|| {
+        let big = vec![1u8; 1 << 20];
+        let small = (0..10).map(|i| vec![i; 8]).collect::<Vec<_>>();
+        big.len() + small.len()
+    });
+    assert_eq!(sum, (1 << 20) + 10);
+    let usage = usage.unwrap();
+    // what was allocated before does not count towards the peak
+    assert!(usage.peak >= 1 << 20 && usage.peak < (1 << 20) + kept.len(), "{:?}", usage);
+    assert!(usage.allocations >= 12, "{:?}", usage);
+}
+
+#[test]
+fn sizes_are_shown_in_binary_units() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(236 << 20).to_string(), "236.0 MiB");
+    assert_eq!(Bytes(5 << 30).to_string(), "5.0 GiB");
+}
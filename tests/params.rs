@@ -0,0 +1,99 @@
+//! Checks how puzzle parameters are read, replaced and rejected.
+
+use std::path::Path;
+use advent_of_code_2022::days;
+use advent_of_code_2022::params::{Param, Params};
+
+const ROW: Param = Param { name: "row", default: "2000000", about: "" };
+const CHECKS: Param = Param { name: "checks", default: "20,60", about: "" };
+
+#[test]
+fn the_command_line_beats_the_file_which_beats_the_default() {
+    assert_eq!(Params::default().value::<isize>(&ROW), Ok(2000000));
+
+    let mut params = Params::parse("# the example\nrow = 10\n\nchecks=1, 2,3\n").unwrap();
+    assert_eq!(params.value::<isize>(&ROW), Ok(10));
+    assert_eq!(params.list::<usize>(&CHECKS), Ok(vec![1, 2, 3]));
+
+    params.set("row", "-4");
+    assert_eq!(params.value::<isize>(&ROW), Ok(-4));
+}
+
+#[test]
+fn malformed_values_point_at_where_they_were_given() {
+    let params = Params::parse("checks = 20\nrow = ten\n").unwrap().in_file(Path::new("day_15.params"));
+    assert_eq!(params.value::<isize>(&ROW).unwrap_err().to_string(),
+        "day_15.params:2:7: expected a number for `row`, found `ten`");
+
+    let mut params = params;
+    params.set("checks", "20,x");
+    assert_eq!(params.list::<usize>(&CHECKS).unwrap_err().to_string(),
+        "expected a number for `checks`, found `x`");
+
+    assert_eq!(Params::parse("row = 1\nrow = 2\n").unwrap_err().to_string(),
+        "2:1: expected `row` only once, found `row`");
+    assert_eq!(Params::parse("row =\n").unwrap_err().to_string(),
+        "1:6: expected a value for `row`, found nothing");
+}
+
+#[test]
+fn only_the_parameters_of_the_day_are_accepted() {
+    let day_15 = days::get(15).unwrap();
+    assert_eq!(day_15.params().iter().map(|param| param.name).collect::<Vec<_>>(), vec!["row", "range"]);
+
+    let params = Params::parse("row = 10\nrows = 20\n").unwrap();
+    assert_eq!(params.check(day_15.params()).unwrap_err().to_string(),
+        "2:1: expected one of `row`, `range`, found `rows`");
+
+    let mut params = Params::default();
+    params.set("minutes", "5");
+    assert_eq!(params.check(days::get(1).unwrap().params()).unwrap_err().to_string(),
+        "expected no parameters, found `minutes`");
+}
+
+#[test]
+fn every_default_is_valid() {
+    for day in days::ALL {
+        for param in day.params() {
+            // numbers, or names like the shapes and symbols of day 2
+            let valid = |value: &str| value.parse::<i64>().is_ok() || (!value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric()));
+            assert!(param.default.split(',').all(valid), "day {} {}", day.number, param.name);
+        }
+    }
+}
+
+#[test]
+fn values_out_of_range_are_rejected_by_the_day() {
+    let configure = |day: u8, input: &str, name: &str, value: &str| {
+        let day = days::get(day).unwrap();
+        let mut solution = day.parse(input).unwrap();
+        let mut params = Params::default();
+        params.set(name, value);
+        day.configure(solution.as_mut(), &params).map_err(|e| e.to_string())
+    };
+    let disk = "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt\n";
+
+    assert_eq!(configure(7, disk, "total_space", "100"),
+        Err("expected a disk of at least the 150 the files take for `total_space`, found `100`".to_string()));
+    assert_eq!(configure(7, disk, "min_needed_space", "70000001"),
+        Err("expected at most the 70000000 of the disk for `min_needed_space`, found `70000001`".to_string()));
+    assert_eq!(configure(7, disk, "min_needed_space", "0"), Ok(()));
+    assert_eq!(configure(10, "noop\n", "crt_width", "0"),
+        Err("expected a width of at least 1 for `crt_width`, found `0`".to_string()));
+    let sensor = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n";
+    assert_eq!(configure(15, sensor, "range", "0"),
+        Err("expected a coordinate of at least 1 for `range`, found `0`".to_string()));
+    let valves = "Valve AA has flow rate=0; tunnels lead to valves BB\nValve BB has flow rate=13; tunnel leads to valve AA\n";
+    assert_eq!(configure(16, valves, "minutes", "31"),
+        Err("expected between 1 and 30 minutes for `minutes`, found `31`".to_string()));
+    assert_eq!(configure(16, valves, "part_two_minutes", "0"),
+        Err("expected between 1 and the 30 minutes until the eruption for `part_two_minutes`, found `0`".to_string()));
+    let blueprint = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. \
+        Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.\n";
+    assert_eq!(configure(19, blueprint, "minutes", "0"),
+        Err("expected between 1 and 32 minutes for `minutes`, found `0`".to_string()));
+    assert_eq!(configure(19, blueprint, "part_two_minutes", "33"),
+        Err("expected between 1 and 32 minutes for `part_two_minutes`, found `33`".to_string()));
+    assert_eq!(configure(19, blueprint, "part_two_blueprints", "100"),
+        Err("expected between 1 and 7 blueprints for `part_two_blueprints`, found `100`".to_string()));
+}
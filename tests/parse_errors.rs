@@ -0,0 +1,66 @@
+//! Feeds malformed inputs to the parsers and checks where the error is reported.
+
+use std::path::Path;
+use advent_of_code_2022::days;
+use advent_of_code_2022::parse::ParseError;
+
+fn parse_error(day: u8, input: &str) -> ParseError {
+    match days::get(day).unwrap().parse(input) {
+        Ok(_) => panic!("day {} accepted {:?}", day, input),
+        Err(e) => e,
+    }
+}
+
+#[test]
+fn reports_line_and_column_of_a_bad_number() {
+    let e = parse_error(1, "1000\n2000\n\n40x0\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (4, 1, "40x0"));
+}
+
+#[test]
+fn reports_the_offending_token() {
+    let e = parse_error(2, "A Y\nB Q\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "Q"));
+
+    let e = parse_error(4, "2-4,6-8\n2-3,4-x\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 7, "x"));
+
+    let e = parse_error(4, "2-4,6-8\n2-3,45\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 7, ""));
+}
+
+#[test]
+fn reports_positions_inside_later_blocks() {
+    let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove one from 1 to 2\n";
+    let e = parse_error(5, input);
+    assert_eq!((e.line, e.column, e.found.as_str()), (6, 6, "one"));
+
+    let e = parse_error(5, "[A]\n 1\n\nmove 1 from 1 to 3\n");
+    assert_eq!((e.line, e.found.as_str()), (4, "3"));
+}
+
+#[test]
+fn reports_inputs_that_end_too_early() {
+    let e = parse_error(9, "R 4\nU\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, ""));
+    assert_eq!(e.to_string(), "2:2: expected the number of steps, found nothing");
+}
+
+#[test]
+fn reports_bad_tokens_in_nested_structures() {
+    let e = parse_error(10, "noop\naddx 3\nmulx 2\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (3, 1, "mulx"));
+
+    let e = parse_error(13, "[1,[2]]\n[1,a]\n");
+    assert_eq!((e.line, e.column, e.found.as_str()), (2, 4, "a"));
+}
+
+#[test]
+fn diagnostic_points_at_the_column() {
+    let input = "A Y\nB Q\n";
+    let e = parse_error(2, input).in_file(Path::new("input/day_02.txt"));
+    assert_eq!(
+        e.diagnostic(input),
+        "input/day_02.txt:2:3: expected one of `X`, `Y`, `Z`, found `Q`\n  |\n2 | B Q\n  |   ^"
+    );
+}
@@ -0,0 +1,106 @@
+//! Worry levels kept whole in part one and modulo the product of all divisors in part two,
+//! instead of a remainder per divisor.
+
+use advent_of_code_2022::differential::Case;
+use advent_of_code_2022::generate::Rng;
+use advent_of_code_2022::Answer;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation { Add(u64), Mul(u64), Square }
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkeys(Vec<Monkey>);
+
+impl Case for Monkeys {
+    fn input(&self) -> String {
+        self.0.iter().enumerate()
+            .map(|(i, m)| {
+                let operation = match m.operation {
+                    Operation::Add(v) => format!("old + {}", v),
+                    Operation::Mul(v) => format!("old * {}", v),
+                    Operation::Square => "old * old".to_string(),
+                };
+                format!("Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                        i, m.items.iter().join(", "), operation, m.divisor, m.if_true, m.if_false)
+            })
+            .join("\n")
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = vec![];
+        for (i, monkey) in self.0.iter().enumerate() {
+            let mut with = |change: &dyn Fn(&mut Monkey)| {
+                let mut monkeys = self.clone();
+                change(&mut monkeys.0[i]);
+                smaller.push(monkeys);
+            };
+            for item in 0..monkey.items.len() {
+                if monkey.items.len() > 1 {
+                    with(&|m| { m.items.remove(item); });
+                }
+                if monkey.items[item] > 1 {
+                    with(&|m| m.items[item] /= 2);
+                }
+            }
+            if !matches!(monkey.operation, Operation::Add(1)) {
+                with(&|m| m.operation = Operation::Add(1));
+            }
+        }
+        smaller
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> Monkeys {
+    let count = rng.between(2, 5);
+    Monkeys((0..count)
+        .map(|i| Monkey {
+            items: (0..rng.between(1, 4)).map(|_| rng.between(1, 99) as u64).collect(),
+            operation: match rng.below(4) {
+                0 => Operation::Square,
+                1 => Operation::Mul(rng.between(2, 19) as u64),
+                _ => Operation::Add(rng.between(1, 8) as u64),
+            },
+            divisor: *rng.pick(&[2, 3, 5, 7, 11, 13, 17, 19, 23]),
+            if_true: (i + rng.between(1, count - 1)) % count,
+            if_false: (i + rng.between(1, count - 1)) % count,
+        })
+        .collect())
+}
+
+/// `None` if a worry level of part one does not fit in 64 bits.
+pub fn reference(monkeys: &Monkeys) -> Option<Vec<Answer>> {
+    let part_one = monkey_business(monkeys, 20, |worry| Some(worry / 3))?;
+    let modulus = monkeys.0.iter().map(|m| m.divisor).product::<u64>();
+    let part_two = monkey_business(monkeys, 10000, |worry| Some(worry % modulus))?;
+    Some(vec![part_one.into(), part_two.into()])
+}
+
+fn monkey_business(monkeys: &Monkeys, rounds: usize, relieve: impl Fn(u64) -> Option<u64>) -> Option<u64> {
+    let mut items = monkeys.0.iter().map(|m| m.items.clone()).collect_vec();
+    let mut inspected = vec![0; items.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.0.iter().enumerate() {
+            for worry in std::mem::take(&mut items[i]) {
+                inspected[i] += 1;
+                let worry = match monkey.operation {
+                    Operation::Add(v) => worry.checked_add(v),
+                    Operation::Mul(v) => worry.checked_mul(v),
+                    Operation::Square => worry.checked_mul(worry),
+                };
+                let worry = relieve(worry?)?;
+                items[if worry % monkey.divisor == 0 { monkey.if_true } else { monkey.if_false }].push(worry);
+            }
+        }
+    }
+    Some(inspected.iter().sorted().rev().take(2).product())
+}
@@ -0,0 +1,118 @@
+//! Every position of the row and of the square checked against every sensor, instead of merging
+//! the ranges the sensors cover.
+
+use advent_of_code_2022::differential::Case;
+use advent_of_code_2022::generate::Rng;
+use advent_of_code_2022::Answer;
+use itertools::Itertools;
+
+type Position = (isize, isize);
+
+#[derive(Clone, Debug)]
+pub struct Sensors {
+    /// Each sensor with its closest beacon.
+    pub sensors: Vec<(Position, Position)>,
+    pub row: isize,
+    pub range: isize,
+}
+
+impl Case for Sensors {
+    fn input(&self) -> String {
+        self.sensors.iter()
+            .map(|((sx, sy), (bx, by))| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sx, sy, bx, by))
+            .collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = (0..self.sensors.len())
+            .map(|i| {
+                let mut sensors = self.clone();
+                sensors.sensors.remove(i);
+                sensors
+            })
+            .collect_vec();
+        if self.row > 0 {
+            smaller.push(Sensors { row: self.row - 1, ..self.clone() });
+        }
+        smaller
+    }
+}
+
+fn distance(a: Position, b: Position) -> isize {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// Sensors that leave exactly one position of the square uncovered: a few random ones, then one
+/// next to every position still uncovered, reaching up to the hidden beacon.
+pub fn generate(rng: &mut Rng) -> Sensors {
+    let range = rng.between(3, 12) as isize;
+    let mut random = |low: isize, high: isize| low + rng.below((high - low + 1) as usize) as isize;
+    let hidden = (random(0, range), random(0, range));
+    let mut sensors = vec![];
+    for _ in 0..random(0, 4) {
+        let sensor = (random(-2, range + 2), random(-2, range + 2));
+        let to_hidden = distance(sensor, hidden);
+        if to_hidden > 1 {
+            add(&mut sensors, sensor, random(1, to_hidden - 1), random(0, 100) as usize);
+        }
+    }
+    for position in (0..=range).cartesian_product(0..=range) {
+        let covered = |sensors: &[(Position, Position)]| sensors.iter().any(|(s, b)| distance(*s, position) <= distance(*s, *b));
+        if position != hidden && !covered(&sensors) {
+            let away = if position.0 != hidden.0 {
+                (position.0 + (position.0 - hidden.0).signum(), position.1)
+            } else {
+                (position.0, position.1 + (position.1 - hidden.1).signum())
+            };
+            add(&mut sensors, away, distance(position, hidden), random(0, 100) as usize);
+        }
+    }
+    Sensors { sensors, row: random(0, range), range }
+}
+
+/// Adds a sensor with one of the beacons `reach` away that keeps every closest beacon unique,
+/// if there is one.
+fn add(sensors: &mut Vec<(Position, Position)>, sensor: Position, reach: isize, pick: usize) {
+    let fits = |beacon: &Position| sensors.iter().all(|(s, b)| {
+        *s != sensor && s != beacon && *b != sensor
+            && (b == beacon || distance(*s, *beacon) > distance(*s, *b))
+            && (b == beacon || distance(sensor, *b) > reach)
+    });
+    let beacons = (-reach..=reach)
+        .flat_map(|dx| [(sensor.0 + dx, sensor.1 + reach - dx.abs()), (sensor.0 + dx, sensor.1 - reach + dx.abs())])
+        .unique()
+        .filter(fits)
+        .collect_vec();
+    if !beacons.is_empty() {
+        sensors.push((sensor, beacons[pick % beacons.len()]));
+    }
+}
+
+/// `None` unless every sensor has a single closest beacon and exactly one position of the
+/// square is out of reach of all of them.
+pub fn reference(case: &Sensors) -> Option<Vec<Answer>> {
+    let sensors = &case.sensors;
+    let beacons = sensors.iter().map(|(_, b)| *b).collect_vec();
+    let reach = |(s, b): &(Position, Position)| distance(*s, *b);
+    let closest_is_unique = sensors.iter().all(|sensor| {
+        beacons.iter().all(|b| *b == sensor.1 || distance(sensor.0, *b) > reach(sensor))
+            && sensors.iter().filter(|other| other.0 == sensor.0).count() == 1
+            && !beacons.contains(&sensor.0)
+    });
+    if !closest_is_unique {
+        return None;
+    }
+    let covered = |position: Position| sensors.iter().any(|sensor| distance(sensor.0, position) <= reach(sensor));
+
+    let widest = sensors.iter().map(reach).max().unwrap_or_default();
+    let (left, right) = sensors.iter().map(|(s, _)| s.0).minmax().into_option().unwrap_or_default();
+    let no_beacon = (left - widest..=right + widest)
+        .map(|x| (x, case.row))
+        .filter(|position| covered(*position) && !beacons.contains(position))
+        .count();
+
+    let (hidden,) = (0..=case.range).cartesian_product(0..=case.range)
+        .filter(|position| !covered(*position))
+        .collect_tuple()?;
+    Some(vec![no_beacon.into(), (hidden.0 * 4000000 + hidden.1).into()])
+}
@@ -0,0 +1,114 @@
+//! Every order of opening the valves tried, with the distances between them from Floyd-Warshall,
+//! instead of A* over the pressure that is missed.
+
+use std::collections::{BTreeSet, HashMap};
+use advent_of_code_2022::differential::Case;
+use advent_of_code_2022::generate::Rng;
+use advent_of_code_2022::Answer;
+use itertools::Itertools;
+
+/// Valve 0 is `AA`.
+#[derive(Clone, Debug)]
+pub struct Valves {
+    rates: Vec<usize>,
+    tunnels: Vec<BTreeSet<usize>>,
+}
+
+fn label(valve: usize) -> String {
+    format!("{}{}", (b'A' + (valve / 26) as u8) as char, (b'A' + (valve % 26) as u8) as char)
+}
+
+impl Case for Valves {
+    fn input(&self) -> String {
+        self.rates.iter().zip(&self.tunnels).enumerate()
+            .map(|(valve, (rate, tunnels))| format!("Valve {} has flow rate={}; tunnels lead to valves {}\n",
+                                                    label(valve), rate, tunnels.iter().map(|t| label(*t)).join(", ")))
+            .collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = vec![];
+        for valve in 1..self.rates.len() {
+            let renumber = |t: &usize| if *t > valve { t - 1 } else { *t };
+            let mut without = self.clone();
+            without.rates.remove(valve);
+            without.tunnels.remove(valve);
+            without.tunnels.iter_mut().for_each(|tunnels| {
+                *tunnels = tunnels.iter().filter(|t| **t != valve).map(renumber).collect();
+            });
+            smaller.push(without);
+            if self.rates[valve] > 0 {
+                let mut lower = self.clone();
+                lower.rates[valve] /= 2;
+                smaller.push(lower);
+            }
+        }
+        for (a, b) in self.tunnels.iter().enumerate().flat_map(|(a, tunnels)| tunnels.iter().map(move |b| (a, *b))) {
+            let mut fewer = self.clone();
+            fewer.tunnels[a].remove(&b);
+            fewer.tunnels[b].remove(&a);
+            smaller.push(fewer);
+        }
+        smaller
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> Valves {
+    let count = rng.between(2, 9);
+    let mut tunnels = vec![BTreeSet::new(); count];
+    for valve in 1..count {
+        let other = rng.below(valve);
+        tunnels[valve].insert(other);
+        tunnels[other].insert(valve);
+    }
+    for _ in 0..rng.below(count) {
+        let (a, b) = (rng.below(count), rng.below(count));
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    }
+    let rates = (0..count).map(|valve| if valve > 0 && rng.chance(60) { rng.between(1, 25) } else { 0 }).collect();
+    Valves { rates, tunnels }
+}
+
+/// `None` if a valve has no tunnels or `AA` has a flow.
+pub fn reference(valves: &Valves) -> Option<Vec<Answer>> {
+    if valves.tunnels.iter().any(BTreeSet::is_empty) || valves.rates[0] > 0 {
+        return None;
+    }
+    let count = valves.rates.len();
+    let mut distance = vec![vec![usize::MAX / 4; count]; count];
+    for (a, tunnels) in valves.tunnels.iter().enumerate() {
+        distance[a][a] = 0;
+        tunnels.iter().for_each(|b| distance[a][*b] = 1);
+    }
+    for (k, a, b) in itertools::iproduct!(0..count, 0..count, 0..count) {
+        distance[a][b] = distance[a][b].min(distance[a][k] + distance[k][b]);
+    }
+
+    let best_per_set = |minutes: usize| {
+        let mut best = HashMap::new();
+        open(valves, &distance, 0, minutes, 0, 0, &mut best);
+        best
+    };
+    let part_one = *best_per_set(30).values().max().unwrap();
+    let with_elephant = best_per_set(26);
+    let part_two = with_elephant.iter().cartesian_product(&with_elephant)
+        .filter(|((mine, _), (theirs, _))| *mine & *theirs == 0)
+        .map(|((_, a), (_, b))| a + b)
+        .max().unwrap();
+    Some(vec![part_one.into(), part_two.into()])
+}
+
+/// Records the most pressure released for every set of valves that can be opened, as a bitmask.
+fn open(valves: &Valves, distance: &[Vec<usize>], at: usize, minutes: usize, opened: u64, released: usize, best: &mut HashMap<u64, usize>) {
+    let entry = best.entry(opened).or_default();
+    *entry = (*entry).max(released);
+    for next in 0..valves.rates.len() {
+        let left = minutes.saturating_sub(distance[at][next] + 1);
+        if valves.rates[next] > 0 && opened & (1 << next) == 0 && left > 0 {
+            open(valves, distance, next, left, opened | (1 << next), released + valves.rates[next] * left, best);
+        }
+    }
+}
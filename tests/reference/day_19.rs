@@ -0,0 +1,116 @@
+//! A depth first search over which robot to build next, only cut short where the geodes with
+//! free ore and clay can not beat the best so far.
+
+use advent_of_code_2022::differential::Case;
+use advent_of_code_2022::generate::Rng;
+use advent_of_code_2022::Answer;
+
+/// Ore for an ore robot, ore for a clay robot, ore and clay for an obsidian robot and ore and
+/// obsidian for a geode robot.
+type Blueprint = [usize; 6];
+
+#[derive(Clone, Debug)]
+pub struct Blueprints(Vec<Blueprint>);
+
+impl Case for Blueprints {
+    fn input(&self) -> String {
+        self.0.iter().enumerate()
+            .map(|(i, [a, b, c, d, e, f])| format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                i + 1, a, b, c, d, e, f))
+            .collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = vec![];
+        for (i, blueprint) in self.0.iter().enumerate() {
+            if self.0.len() > 1 {
+                let mut fewer = self.clone();
+                fewer.0.remove(i);
+                smaller.push(fewer);
+            }
+            for (cost, amount) in blueprint.iter().enumerate() {
+                if *amount > 1 {
+                    let mut cheaper = self.clone();
+                    cheaper.0[i][cost] -= 1;
+                    smaller.push(cheaper);
+                }
+            }
+        }
+        smaller
+    }
+}
+
+/// The geodes if ore and clay were free and an obsidian robot was built every minute along with
+/// a geode robot whenever there is enough obsidian, which no plan can beat.
+fn optimistic(geode_obsidian: usize, minutes: usize, robots: Amounts, stock: Amounts) -> usize {
+    let ([_, _, mut obsidian_robots, mut geode_robots], [_, _, mut obsidian, mut geodes]) = (robots, stock);
+    for _ in 0..minutes {
+        let build = obsidian >= geode_obsidian;
+        if build {
+            obsidian -= geode_obsidian;
+        }
+        obsidian += obsidian_robots;
+        geodes += geode_robots;
+        obsidian_robots += 1;
+        geode_robots += usize::from(build);
+    }
+    geodes
+}
+
+pub fn generate(rng: &mut Rng) -> Blueprints {
+    Blueprints((0..rng.between(1, 2))
+        .map(|_| [rng.between(2, 4), rng.between(2, 4), rng.between(2, 4), rng.between(5, 20), rng.between(2, 4), rng.between(7, 20)])
+        .collect())
+}
+
+pub fn reference(blueprints: &Blueprints) -> Option<Vec<Answer>> {
+    let quality = blueprints.0.iter().enumerate().map(|(i, b)| (i + 1) * geodes(b, 24)).sum::<usize>();
+    let product = blueprints.0.iter().take(3).map(|b| geodes(b, 32)).product::<usize>();
+    Some(vec![quality.into(), product.into()])
+}
+
+fn geodes(blueprint: &Blueprint, minutes: usize) -> usize {
+    let mut best = 0;
+    build(blueprint, minutes, [1, 0, 0, 0], [0; 4], &mut best);
+    best
+}
+
+/// Ore, clay, obsidian and geode.
+type Amounts = [usize; 4];
+
+fn build(blueprint: &Blueprint, minutes: usize, robots: Amounts, stock: Amounts, best: &mut usize) {
+    let [ore_robot, clay_robot, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = *blueprint;
+    *best = (*best).max(stock[3] + robots[3] * minutes);
+    if optimistic(geode_obsidian, minutes, robots, stock) <= *best {
+        return;
+    }
+    let costs = [[ore_robot, 0, 0], [clay_robot, 0, 0], [obsidian_ore, obsidian_clay, 0], [geode_ore, 0, geode_obsidian]];
+    // more robots than can be spent in a minute, or more stock than can be spent in the minutes
+    // left, would never help
+    let most_spent = [ore_robot.max(clay_robot).max(obsidian_ore).max(geode_ore), obsidian_clay, geode_obsidian];
+    for (robot, cost) in costs.iter().enumerate() {
+        if robot < 3 && (robots[robot] >= most_spent[robot] || stock[robot] >= most_spent[robot] * minutes) {
+            continue;
+        }
+        // minutes to wait for the resources, if the robots for them exist
+        let wait = (0..3)
+            .map(|r| match (cost[r].saturating_sub(stock[r]), robots[r]) {
+                (0, _) => Some(0),
+                (_, 0) => None,
+                (missing, n) => Some(missing.div_ceil(n)),
+            })
+            .collect::<Option<Vec<_>>>()
+            .and_then(|waits| waits.into_iter().max());
+        let Some(wait) = wait.filter(|wait| wait + 1 < minutes) else {
+            continue;
+        };
+        let mut next_stock = stock;
+        (0..4).for_each(|r| next_stock[r] += robots[r] * (wait + 1));
+        (0..3).for_each(|r| next_stock[r] -= cost[r]);
+        let mut next_robots = robots;
+        next_robots[robot] += 1;
+        build(blueprint, minutes - wait - 1, next_robots, next_stock, best);
+    }
+}
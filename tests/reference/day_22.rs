@@ -0,0 +1,200 @@
+//! Walking the flat map by scanning back to the other end of a row or column, and the cube by
+//! folding the net in three dimensions, instead of the seams of one particular net.
+
+use std::collections::HashMap;
+use advent_of_code_2022::differential::Case;
+use advent_of_code_2022::generate::Rng;
+use advent_of_code_2022::Answer;
+use itertools::Itertools;
+
+/// The faces of the net the solution folds, in units of the edge.
+const FACES: [(usize, usize); 6] = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    edge: usize,
+    walls: Vec<(usize, usize)>,
+    /// Moves, with a turn between each two.
+    moves: Vec<usize>,
+    turns: Vec<char>,
+}
+
+impl Board {
+    fn tiles(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        FACES.iter().flat_map(move |(fx, fy)| (0..self.edge).cartesian_product(0..self.edge)
+            .map(move |(x, y)| (fx * self.edge + x, fy * self.edge + y)))
+    }
+}
+
+impl Case for Board {
+    fn input(&self) -> String {
+        let width = FACES.iter().map(|(fx, _)| fx + 1).max().unwrap() * self.edge;
+        let mut rows = vec![vec![' '; width]; 4 * self.edge];
+        self.tiles().for_each(|(x, y)| rows[y][x] = if self.walls.contains(&(x, y)) { '#' } else { '.' });
+        let map = rows.iter().map(|row| row.iter().collect::<String>().trim_end().to_string()).join("\n");
+        let path = self.moves.iter().map(|m| m.to_string()).interleave(self.turns.iter().map(|t| t.to_string())).collect::<String>();
+        format!("{}\n\n{}\n", map, path)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut smaller = vec![];
+        for i in 0..self.walls.len() {
+            let mut fewer = self.clone();
+            fewer.walls.remove(i);
+            smaller.push(fewer);
+        }
+        for i in 1..self.moves.len() {
+            let mut shorter = self.clone();
+            shorter.moves.remove(i);
+            shorter.turns.remove(i - 1);
+            smaller.push(shorter);
+        }
+        for i in 0..self.moves.len() {
+            if self.moves[i] > 0 {
+                let mut shorter = self.clone();
+                shorter.moves[i] -= 1;
+                smaller.push(shorter);
+            }
+        }
+        smaller
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> Board {
+    let mut board = Board { edge: rng.between(2, 4), walls: vec![], moves: vec![], turns: vec![] };
+    board.walls = board.tiles().collect_vec().into_iter().filter(|_| rng.chance(15)).collect();
+    board.moves = (0..rng.between(1, 12)).map(|_| rng.between(0, 3 * board.edge)).collect();
+    board.turns = (1..board.moves.len()).map(|_| *rng.pick(&['L', 'R'])).collect();
+    board
+}
+
+/// `None` if the top row has no open tile to start on.
+pub fn reference(board: &Board) -> Option<Vec<Answer>> {
+    let start = board.tiles().filter(|(x, y)| *y == 0 && !board.walls.contains(&(*x, *y))).min()?;
+    Some(vec![walk_flat(board, start).into(), walk_cube(board, start).into()])
+}
+
+type Point = (isize, isize);
+
+fn password((x, y): Point, facing: usize) -> isize {
+    1000 * (y + 1) + 4 * (x + 1) + facing as isize
+}
+
+/// Right, down, left and up, the order of the facing in the password.
+const DIRECTIONS: [Point; 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+fn walk_flat(board: &Board, start: (usize, usize)) -> isize {
+    let tiles = board.tiles().map(|(x, y)| ((x as isize, y as isize), board.walls.contains(&(x, y)))).collect::<HashMap<_, _>>();
+    let (mut at, mut facing) = ((start.0 as isize, start.1 as isize), 0);
+    for (i, steps) in board.moves.iter().enumerate() {
+        let (dx, dy) = DIRECTIONS[facing];
+        for _ in 0..*steps {
+            let mut next = (at.0 + dx, at.1 + dy);
+            if !tiles.contains_key(&next) {
+                next = at;
+                while tiles.contains_key(&(next.0 - dx, next.1 - dy)) {
+                    next = (next.0 - dx, next.1 - dy);
+                }
+            }
+            if tiles[&next] {
+                break;
+            }
+            at = next;
+        }
+        facing = turn(facing, board.turns.get(i));
+    }
+    password(at, facing)
+}
+
+fn turn(facing: usize, turn: Option<&char>) -> usize {
+    match turn {
+        Some('R') => (facing + 1) % 4,
+        Some(_) => (facing + 3) % 4,
+        None => facing,
+    }
+}
+
+type Vector = [isize; 3];
+
+fn plus(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn times(a: Vector, k: isize) -> Vector {
+    [a[0] * k, a[1] * k, a[2] * k]
+}
+
+/// Where a face of the net ended up on the cube: the corner of its top left tile, the
+/// directions of its rows and columns, and the direction pointing out of the cube.
+#[derive(Clone, Copy)]
+struct Face { corner: Vector, right: Vector, down: Vector, out: Vector }
+
+/// Folds the net onto a cube from 0 to twice the edge on every axis, so the middle of every
+/// tile has whole coordinates.
+fn fold(board: &Board) -> HashMap<(usize, usize), Face> {
+    let side = 2 * board.edge as isize;
+    let mut faces = HashMap::from([(FACES[0], Face { corner: [0, 0, 0], right: [1, 0, 0], down: [0, 1, 0], out: [0, 0, -1] })]);
+    let mut todo = vec![FACES[0]];
+    while let Some((fx, fy)) = todo.pop() {
+        let Face { corner, right, down, out } = faces[&(fx, fy)];
+        let inward = times(out, -1);
+        let neighbours = [
+            ((fx + 1, fy), Face { corner: plus(corner, times(right, side)), right: inward, down, out: right }),
+            ((fx.wrapping_sub(1), fy), Face { corner: plus(corner, times(inward, side)), right: out, down, out: times(right, -1) }),
+            ((fx, fy + 1), Face { corner: plus(corner, times(down, side)), right, down: inward, out: down }),
+            ((fx, fy.wrapping_sub(1)), Face { corner: plus(corner, times(inward, side)), right, down: out, out: times(down, -1) }),
+        ];
+        for (position, face) in neighbours {
+            if FACES.contains(&position) && !faces.contains_key(&position) {
+                faces.insert(position, face);
+                todo.push(position);
+            }
+        }
+    }
+    faces
+}
+
+fn walk_cube(board: &Board, start: (usize, usize)) -> isize {
+    let edge = board.edge;
+    let side = 2 * edge as isize;
+    let faces = fold(board);
+    let face_of = |(x, y): (usize, usize)| faces[&(x / edge, y / edge)];
+    let middle = |(x, y): (usize, usize)| {
+        let face = face_of((x, y));
+        let across = plus(times(face.right, 2 * (x % edge) as isize + 1), times(face.down, 2 * (y % edge) as isize + 1));
+        plus(face.corner, across)
+    };
+    let tiles = board.tiles().map(|tile| (middle(tile), tile)).collect::<HashMap<_, _>>();
+
+    let (mut at, mut heading) = (start, face_of(start).right);
+    for (i, steps) in board.moves.iter().enumerate() {
+        for _ in 0..*steps {
+            let here = middle(at);
+            let mut next = plus(here, times(heading, 2));
+            let mut next_heading = heading;
+            if next.iter().any(|c| !(0..=side).contains(c)) {
+                // over the edge onto the neighbouring face, heading into the cube
+                let out = face_of(at).out;
+                next = plus(plus(here, heading), times(out, -1));
+                next_heading = times(out, -1);
+            }
+            let tile = tiles[&next];
+            if board.walls.contains(&tile) {
+                break;
+            }
+            (at, heading) = (tile, next_heading);
+        }
+        if let Some(direction) = board.turns.get(i) {
+            let headings = headings(face_of(at));
+            let facing = headings.iter().position(|h| *h == heading).unwrap();
+            heading = headings[turn(facing, Some(direction))];
+        }
+    }
+    let facing = headings(face_of(at)).iter().position(|h| *h == heading).unwrap();
+    password((at.0 as isize, at.1 as isize), facing)
+}
+
+/// The directions of a face in the order of the facing in the password.
+fn headings(face: Face) -> [Vector; 4] {
+    [face.right, face.down, times(face.right, -1), times(face.down, -1)]
+}
@@ -0,0 +1,72 @@
+//! Drives the simulations of the days through the REPL, like someone typing the commands.
+
+use advent_of_code_2022::{days, input, Part, Solution};
+use advent_of_code_2022::repl::Repl;
+
+fn sample(day: u8) -> Box<dyn Solution> {
+    let path = input::repo_dir().join(format!("input/day_{:02}_sample.txt", day));
+    let input = input::resolve(day, Some(&path)).unwrap();
+    days::get(day).unwrap().parse(&input.text).unwrap()
+}
+
+fn session(solution: &dyn Solution, part: Part, commands: &str) -> String {
+    let mut output = vec![];
+    Repl::new(|| solution.simulate(part).unwrap()).run(commands.as_bytes(), &mut output, false).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn the_rope_is_stepped_through_and_started_over() {
+    let solution = sample(9);
+    let output = session(solution.as_ref(), Part::One, "\
+step 5
+inspect knot
+run until visited >= 8
+run
+inspect visited
+reset
+show
+");
+    assert_eq!(output, "\
+at step 5
+step = 5
+knot H at 4,-1
+knot T at 3,0
+visited >= 8 at step 10
+over at step 24
+step = 24
+visited = 13
+at step 0
+H
+");
+}
+
+#[test]
+fn running_to_the_end_gives_the_answer() {
+    let solution = sample(23);
+    let output = session(solution.as_ref(), Part::Two, "run\ninspect round\nstep\n");
+    assert_eq!(output, "over at step 20\nstep = 20\nround = 20\nover at step 20\n");
+    assert_eq!(solution.part_two().as_str(), "20");
+}
+
+#[test]
+fn mistyped_commands_are_explained() {
+    let solution = sample(10);
+    let output = session(solution.as_ref(), Part::One, "jump\nstep two\nrun until x ~ 3\nrun until nothing == 1\nquit\nshow\n");
+    assert_eq!(output, "\
+expected one of `step`, `run`, `show`, `inspect`, `reset`, `help`, `quit`, found `jump`
+expected a number of steps, found `two`
+expected one of `==`, `!=`, `<`, `<=`, `>`, `>=`, found `~`
+over at step 240 without nothing == 1
+");
+}
+
+#[test]
+fn only_the_simulating_days_can_be_stepped_through() {
+    for day in [9, 10, 14, 23, 24] {
+        assert!(sample(day).simulate(Part::One).is_some(), "day {}", day);
+    }
+    for day in [3, 12, 22] {
+        assert!(sample(day).simulate(Part::One).is_none(), "day {}", day);
+    }
+}
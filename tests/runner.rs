@@ -0,0 +1,92 @@
+//! Checks that running the calendar reports every day, whatever state it is in.
+
+use std::fs;
+use std::sync::Mutex;
+use std::time::Duration;
+use advent_of_code_2022::answers::AnswerRegistry;
+use advent_of_code_2022::days;
+use advent_of_code_2022::input;
+use advent_of_code_2022::runner::{self, Status};
+use advent_of_code_2022::Part;
+
+/// Held while the input directory is looked up or pointed somewhere else.
+static ENV: Mutex<()> = Mutex::new(());
+
+#[test]
+fn finished_unfinished_and_missing_days_are_told_apart() {
+    let dir = std::env::temp_dir().join(format!("aoc_runner_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("day_17.txt"), ">><<\n").unwrap();
+    let registry = AnswerRegistry::load(input::repo_dir().join("answers.txt")).unwrap();
+
+    let _env = ENV.lock().unwrap();
+    std::env::set_var(input::DIR_VAR, &dir);
+    let selected = [days::get(1).unwrap(), days::get(17).unwrap(), days::get(18).unwrap()];
+    let runs = selected.iter().flat_map(|day| runner::run(day, &registry, None)).collect::<Vec<_>>();
+    std::env::remove_var(input::DIR_VAR);
+
+    let statuses = runs.iter().map(|run| (run.day, run.part, run.status.clone())).collect::<Vec<_>>();
+    assert_eq!(statuses, vec![
+        (1, Part::One, Status::Ok),
+        (1, Part::Two, Status::Ok),
+        (17, Part::One, Status::Unimplemented),
+        (17, Part::Two, Status::Unimplemented),
+        (18, Part::One, Status::NoInput),
+        (18, Part::Two, Status::NoInput),
+    ]);
+    assert!(runs.iter().all(|run| !run.status.is_regression()));
+}
+
+#[test]
+fn inputs_that_cannot_be_used_get_rows_of_their_own() {
+    let dir = std::env::temp_dir().join(format!("aoc_runner_skipped_{}", std::process::id()));
+    fs::create_dir_all(dir.join("day_01")).unwrap();
+    fs::write(dir.join("day_01").join("alice.txt"), "1\n2\n\n3\n").unwrap();
+    fs::write(dir.join("day_01").join("bob.txt"), "4\n").unwrap();
+    fs::write(dir.join("day_01").join("bob.params"), "row 10\n").unwrap();
+    fs::write(dir.join("day_01").join("carol.txt"), "").unwrap();
+    let registry = AnswerRegistry::load(input::repo_dir().join("answers.txt")).unwrap();
+
+    let _env = ENV.lock().unwrap();
+    std::env::set_var(input::DIR_VAR, &dir);
+    let runs = runner::run(days::get(1).unwrap(), &registry, None);
+    std::env::remove_var(input::DIR_VAR);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let rows = runs.iter()
+        .map(|run| (run.input.as_ref().unwrap().file_name().unwrap().to_str().unwrap(), run.part, run.status.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![
+        ("alice.txt", Part::One, "missing".to_string()),
+        ("alice.txt", Part::Two, "missing".to_string()),
+        ("bob.txt", Part::One, "invalid input".to_string()),
+        ("bob.txt", Part::Two, "invalid input".to_string()),
+        ("carol.txt", Part::One, "no input".to_string()),
+        ("carol.txt", Part::Two, "no input".to_string()),
+    ]);
+    assert!(matches!(&runs[2].status, Status::InvalidInput(e) if e.contains("found `row 10`")));
+    assert!(runs[2].status.is_regression() && !runs[4].status.is_regression());
+}
+
+#[test]
+fn a_wrong_answer_is_a_regression() {
+    let path = std::env::temp_dir().join(format!("aoc_runner_answers_{}.txt", std::process::id()));
+    fs::write(&path, "02\t1\tinput/day_02.txt\t1\n").unwrap();
+    let registry = AnswerRegistry::load(&path).unwrap();
+
+    let _env = ENV.lock().unwrap();
+    let runs = runner::run_all(&days::ALL[1..2], &registry, true, None);
+    assert_eq!(runs[0].status, Status::Wrong);
+    assert!(runs[0].status.is_regression());
+    assert_eq!(runs[1].status, Status::Missing);
+    assert!(!runs[1].status.is_regression());
+}
+
+#[test]
+fn a_part_over_its_budget_times_out_without_failing_the_run() {
+    let registry = AnswerRegistry::load(input::repo_dir().join("answers.txt")).unwrap();
+    let _env = ENV.lock().unwrap();
+    let runs = runner::run(days::get(16).unwrap(), &registry, Some(Duration::ZERO));
+    assert_eq!(runs.iter().map(|run| run.status.clone()).collect::<Vec<_>>(), vec![Status::Timeout, Status::Timeout]);
+    assert!(runs.iter().all(|run| run.answer.is_none() && !run.status.is_regression()));
+}
@@ -0,0 +1,126 @@
+//! Runs every day on its `input/day_XX_sample.txt` and checks the example answers from the puzzle text.
+
+use advent_of_code_2022::{input, Answer, Solution};
+use advent_of_code_2022::days::*;
+
+/// The sample of `day` with the parameters of its `.params` file, if it has one.
+fn sample<S: Solution>(day: u8) -> S {
+    let path = input::repo_dir().join(format!("input/day_{:02}_sample.txt", day));
+    let input = input::resolve(day, Some(&path)).unwrap();
+    input.params.check(S::params()).unwrap();
+    S::load(&input.text, &input.params).unwrap()
+}
+
+fn check<S: Solution>(day: u8, part_one: impl Into<Answer>, part_two: impl Into<Answer>) {
+    let solution = sample::<S>(day);
+    assert_eq!(solution.part_one(), part_one.into(), "day {} part 1", day);
+    assert_eq!(solution.part_two(), part_two.into(), "day {} part 2", day);
+}
+
+#[test]
+fn day_03() {
+    check::<day_03::Day03>(3, 157, 70);
+}
+
+#[test]
+fn day_04() {
+    check::<day_04::Day04>(4, 2, 4);
+}
+
+#[test]
+fn day_05() {
+    check::<day_05::Day05>(5, "CMZ", "MCD");
+}
+
+#[test]
+fn day_06() {
+    check::<day_06::Day06>(6, 7, 19);
+}
+
+#[test]
+fn day_07() {
+    check::<day_07::Day07>(7, 95437, 24933642);
+}
+
+#[test]
+fn day_08() {
+    check::<day_08::Day08>(8, 21, 8);
+}
+
+#[test]
+fn day_09() {
+    check::<day_09::Day09>(9, 13, 1);
+}
+
+#[test]
+fn day_10() {
+    let crt = "
+        ██__██__██__██__██__██__██__██__██__██__
+        ███___███___███___███___███___███___███_
+        ████____████____████____████____████____
+        █████_____█████_____█████_____█████_____
+        ██████______██████______██████______████
+        ███████_______███████_______███████_____";
+    check::<day_10::Day10>(10, 13140, crt.replace(' ', "").trim());
+}
+
+#[test]
+fn day_11() {
+    check::<day_11::Day11>(11, 10605, 2713310158_u64);
+}
+
+#[test]
+fn day_12() {
+    check::<day_12::Day12>(12, 31, 29);
+}
+
+#[test]
+fn day_13() {
+    check::<day_13::Day13>(13, 13, 140);
+}
+
+#[test]
+fn day_14() {
+    check::<day_14::Day14>(14, 24, 93);
+}
+
+#[test]
+fn day_15() {
+    check::<day_15::Day15>(15, 26, 56000011);
+}
+
+#[test]
+fn day_16() {
+    check::<day_16::Day16>(16, 1651, 1707);
+}
+
+#[test]
+fn day_19() {
+    check::<day_19::Day19>(19, 33, 56 * 62);
+}
+
+#[test]
+fn day_22_part_one() {
+    assert_eq!(sample::<day_22::Day22>(22).part_one(), 6032.into());
+}
+
+#[test]
+#[ignore = "the cube seams are hardcoded for the layout of the real input"]
+fn day_22_part_two() {
+    assert_eq!(sample::<day_22::Day22>(22).part_two(), 5031.into());
+}
+
+#[test]
+fn day_23() {
+    check::<day_23::Day23>(23, 110, 20);
+}
+
+#[test]
+fn day_24() {
+    check::<day_24::Day24>(24, 18, 54);
+}
+
+#[test]
+fn day_25() {
+    assert_eq!(sample::<day_25::Day25>(25).part_one(), "2=-1=0".into());
+}
@@ -0,0 +1,55 @@
+//! Checks that a new day is generated and registered in order, and that nothing gets overwritten.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use advent_of_code_2022::input;
+use advent_of_code_2022::scaffold;
+
+/// A copy of the files the scaffolding touches, without day 17.
+fn repo_without_day_17(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for dir in ["src/days", "input", "tests"] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    let registry = fs::read_to_string(input::repo_dir().join("src/days/mod.rs")).unwrap();
+    let registry = registry.lines().filter(|line| !line.contains("day_17::")).collect::<Vec<_>>().join("\n");
+    fs::write(root.join("src/days/mod.rs"), registry).unwrap();
+    fs::copy(input::repo_dir().join("tests/samples.rs"), root.join("tests/samples.rs")).unwrap();
+    root
+}
+
+#[test]
+fn a_new_day_is_registered_between_its_neighbours() {
+    let root = repo_without_day_17("new");
+    let written = scaffold::new_day(&root, 17).unwrap();
+    assert_eq!(written.len(), 5);
+
+    let module = fs::read_to_string(root.join("src/days/day_17.rs")).unwrap();
+    assert!(module.contains("impl Solution for Day17"));
+    assert_eq!(fs::read_to_string(root.join("input/day_17_sample.txt")).unwrap(), "");
+
+    let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(registry.contains("    16 => day_16::Day16,\n    17 => day_17::Day17,\n    18 => day_18::Day18,"));
+
+    let tests = fs::read_to_string(root.join("tests/samples.rs")).unwrap();
+    let (before, after) = tests.split_once("fn day_17()").unwrap();
+    assert!(before.contains("fn day_16()") && after.contains("fn day_19()"));
+}
+
+#[test]
+fn existing_files_are_never_overwritten() {
+    let root = repo_without_day_17("existing");
+    fs::write(root.join("input/day_17.txt"), "my input").unwrap();
+    let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+
+    let e = scaffold::new_day(&root, 17).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(fs::read_to_string(root.join("input/day_17.txt")).unwrap(), "my input");
+    assert!(!root.join("src/days/day_17.rs").exists());
+    assert_eq!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap(), registry);
+
+    let e = scaffold::new_day(&root, 16).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::AlreadyExists);
+}
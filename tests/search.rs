@@ -0,0 +1,126 @@
+//! Checks the search strategies against each other on a small problem with a known answer.
+
+use advent_of_code_2022::cancel::{Cancelled, Token};
+use advent_of_code_2022::search::{self, SearchProblem, Strategy};
+
+/// Walking from 0 to `goal` with steps of 1 costing 2 or jumps of 3 costing 7, so the cheapest
+/// path takes the most steps.
+struct Walk {
+    goal: usize,
+    /// Multiplies the lower bound of 2 per unit of distance left.
+    heuristic_factor: usize,
+}
+
+impl SearchProblem for Walk {
+    type State = usize;
+
+    fn start(&self) -> usize {
+        0
+    }
+
+    fn successors(&self, x: &usize) -> Vec<(usize, usize)> {
+        [(x + 1, 2), (x + 3, 7)].into_iter().filter(|(next, _)| *next <= self.goal).collect()
+    }
+
+    fn is_goal(&self, x: &usize) -> bool {
+        *x == self.goal
+    }
+
+    fn heuristic(&self, x: &usize) -> usize {
+        (self.goal - x) * 2 * self.heuristic_factor
+    }
+}
+
+#[test]
+fn optimal_strategies_agree_on_the_cost() {
+    let walk = Walk { goal: 10, heuristic_factor: 1 };
+    for strategy in [Strategy::Dijkstra, Strategy::AStar, Strategy::BranchAndBound] {
+        let report = search::search(&walk, strategy);
+        let path = report.path.unwrap();
+        assert_eq!(path.cost, 20, "{}", strategy);
+        assert_eq!(path.states, (0..=10).collect::<Vec<_>>(), "{}", strategy);
+        assert!(report.stats.expanded > 0 && report.stats.peak_frontier > 0, "{}", strategy);
+    }
+}
+
+#[test]
+fn bfs_takes_the_fewest_steps_whatever_they_cost() {
+    let path = search::search(&Walk { goal: 10, heuristic_factor: 1 }, Strategy::Bfs).path.unwrap();
+    assert_eq!(path.states.len(), 5);
+    assert_eq!(path.cost, 23);
+}
+
+#[test]
+fn a_heuristic_saves_expansions() {
+    /// Walking on 0..=30 in both directions from the middle to the end.
+    struct Line;
+
+    impl SearchProblem for Line {
+        type State = usize;
+
+        fn start(&self) -> usize {
+            15
+        }
+
+        fn successors(&self, x: &usize) -> Vec<(usize, usize)> {
+            [x.checked_sub(1), Some(x + 1).filter(|next| *next <= 30)].into_iter().flatten().map(|next| (next, 1)).collect()
+        }
+
+        fn is_goal(&self, x: &usize) -> bool {
+            *x == 30
+        }
+
+        fn heuristic(&self, x: &usize) -> usize {
+            30 - x
+        }
+    }
+
+    let dijkstra = search::search(&Line, Strategy::Dijkstra).stats;
+    let astar = search::search(&Line, Strategy::AStar).stats;
+    assert_eq!(astar.expanded, 15);
+    assert!(astar.expanded < dijkstra.expanded, "{:?} vs {:?}", astar, dijkstra);
+}
+
+#[test]
+fn an_unreachable_goal_has_no_path() {
+    /// Looks for a point past the end of the walk.
+    struct Beyond(Walk);
+
+    impl SearchProblem for Beyond {
+        type State = usize;
+
+        fn start(&self) -> usize {
+            self.0.start()
+        }
+
+        fn successors(&self, x: &usize) -> Vec<(usize, usize)> {
+            self.0.successors(x)
+        }
+
+        fn is_goal(&self, x: &usize) -> bool {
+            *x > self.0.goal
+        }
+    }
+
+    let beyond = Beyond(Walk { goal: 10, heuristic_factor: 1 });
+    for strategy in [Strategy::Bfs, Strategy::Dijkstra, Strategy::AStar, Strategy::BranchAndBound] {
+        assert_eq!(search::search(&beyond, strategy).path, None, "{}", strategy);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not admissible")]
+fn an_overestimating_heuristic_is_caught_in_debug_builds() {
+    search::search(&Walk { goal: 10, heuristic_factor: 3 }, Strategy::AStar);
+}
+
+#[test]
+fn a_cancelled_search_gives_up() {
+    let token = Token::none();
+    token.cancel();
+    let walk = Walk { goal: 10, heuristic_factor: 1 };
+    for strategy in [Strategy::Bfs, Strategy::Dijkstra, Strategy::AStar, Strategy::BranchAndBound] {
+        assert_eq!(search::search_until(&walk, strategy, &token).err(), Some(Cancelled), "{}", strategy);
+    }
+}
@@ -0,0 +1,45 @@
+//! Checks that events are filtered by level and written to the trace file.
+
+use std::fmt::{Display, Formatter};
+use std::fs;
+use advent_of_code_2022::trace::{self, Event, Level};
+
+struct Step(usize);
+
+impl Event for Step {
+    const LEVEL: Level = Level::Debug;
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "step {}", self.0)
+    }
+}
+
+struct Done;
+
+impl Event for Done {
+    const LEVEL: Level = Level::Info;
+}
+
+impl Display for Done {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "done")
+    }
+}
+
+#[test]
+fn writes_enabled_events_to_the_trace_file() {
+    let path = std::env::temp_dir().join(format!("aoc-trace-{}.log", std::process::id()));
+    trace::init(1, Some(&path)).unwrap();
+
+    let mut built = false;
+    trace::emit(|| { built = true; Step(1) });
+    trace::emit(|| Done);
+    trace::flush();
+
+    assert!(!built, "filtered events should not be built");
+    assert!(trace::enabled(Level::Info) && !trace::enabled(Level::Debug));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "[info trace] done\n");
+    fs::remove_file(path).unwrap();
+}
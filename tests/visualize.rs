@@ -0,0 +1,53 @@
+//! Checks the files the frame sinks write.
+
+use std::fs;
+use std::path::PathBuf;
+use advent_of_code_2022::grid::Point;
+use advent_of_code_2022::visualize::{self, text_frame, Format};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc_visualize_{}_{}", name, std::process::id()))
+}
+
+#[test]
+fn text_frames_are_padded_to_the_longest_line() {
+    let frame = text_frame("#.\n#..#\n");
+    assert_eq!((frame.width(), frame.height()), (4, 2));
+    assert_eq!(frame[Point::new(3, 0)], ' ');
+    assert_eq!(frame[Point::new(3, 1)], '#');
+}
+
+#[test]
+fn ascii_and_image_sinks_write_a_numbered_file_per_frame() {
+    let dir = scratch_dir("files");
+    for format in [Format::Ascii, Format::Ppm, Format::Pgm] {
+        let mut sink = visualize::sink(format, &dir, "test").unwrap();
+        sink.push(&text_frame("#.\n.E\n")).unwrap();
+        sink.push(&text_frame("..\n.#\n")).unwrap();
+        sink.finish().unwrap();
+    }
+    assert_eq!(fs::read_to_string(dir.join("test_0002.txt")).unwrap(), "..\n.#\n");
+
+    let ppm = fs::read(dir.join("test_0001.ppm")).unwrap();
+    assert!(ppm.starts_with(b"P6\n8 8\n255\n"));
+    assert_eq!(ppm.len(), "P6\n8 8\n255\n".len() + 8 * 8 * 3);
+    let pgm = fs::read(dir.join("test_0002.pgm")).unwrap();
+    assert_eq!(pgm.len(), "P5\n8 8\n255\n".len() + 8 * 8);
+}
+
+#[test]
+fn asciicast_recordings_hold_every_frame() {
+    let dir = scratch_dir("cast");
+    visualize::start(visualize::sink(Format::Asciicast, &dir, "test").unwrap());
+    visualize::frame(|| text_frame("#\n"));
+    visualize::frame(|| text_frame("..\n.#\n"));
+    assert_eq!(visualize::finish().unwrap(), 2);
+
+    let cast = fs::read_to_string(dir.join("test.cast")).unwrap();
+    let lines = cast.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], r#"{"version": 2, "width": 2, "height": 2}"#);
+    assert_eq!(lines[2], r#"[0.1,"o","\u001b[H\u001b[2J..\r\n.#"]"#);
+    assert_eq!(lines.len(), 3);
+
+    visualize::frame(|| panic!("frames are only built while a sink is installed"));
+}